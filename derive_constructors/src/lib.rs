@@ -31,6 +31,11 @@
 //! let expected_character = CharacterInfo { name: "Jorge".to_string(), age: 23, times_appeared: 0, years_studied: 4};
 //! assert_eq!(character_using_from, expected_character);
 //! ```
+//!
+//! Generic structs are supported too, the generated implementations keep the struct's generics and
+//! where clauses, [PhantomData](core::marker::PhantomData) fields are always initialized as
+//! PhantomData instead of being asked for, and fields initialized through [Default::default] whose
+//! type depends on a generic parameter get a ```FieldType: Default``` bound.
 //! ## 2 The Attribute Macro for structs:
 //! > Ref: [derive_constructors_proc::constructor]
 //!
@@ -39,36 +44,218 @@
 //! optional):
 //!
 //! - named: Name of the function, constructor functions are usually named like
-//! 'with_*name of the fields*', as calling them are quite readable, like
-//! ```CharacterInfo::with_name_and_age("Jorge", 23)```. <br> Note: If this field isn't given,
-//! instead of implementing a 'with_*' constructor function, it will implement the [From] or
-//! [TryFrom] trait.
-//!
-//! - pattern (values: [From, TryFrom], default: From):
+//!   'with_*name of the fields*', as calling them are quite readable, like
+//!   ```CharacterInfo::with_name_and_age("Jorge", 23)```. <br> Note: If this field isn't given,
+//!   instead of implementing a 'with_*' constructor function, it will implement the [From] or
+//!   [TryFrom] trait. <br> Giving ```named(auto)``` names the function after the fields it receives,
+//!   like ```with_name_and_age```, or ```try_with_name_and_age``` for the TryFrom pattern (whose
+//!   error enum is named after it by default), where the ```named_prefix``` and ```named_joiner```
+//!   attributes replace ```with``` and ```and```, as in ```named_prefix(from), named_joiner(plus)```
+//!   giving ```from_name_plus_age```, while a constructor without fields is named ```new```, these
+//!   two attributes can only be given along with ```named(auto)```.
+//!
+//! - pattern (values: [From, TryFrom, Into, Builder, TypestateBuilder, FromStr], default: From):
 //!     - When using the From pattern, the function receives fields as parameters and returns this
-//! struct with said values, this is what you'll be looking for most of the time.
+//!       struct with said values, this is what you'll be looking for most of the time.
 //!     - When using the TryFrom pattern, the functions receives types that implement
-//! Into<YourField1>, Into<YourField2>..., returning a [Ok] with your struct if every field could
-//! successfully be turned to your field, in case not, it will return [Err] with an enum telling
-//! which field couldn't get initialized and the Error why it didn't, see examples below for this.
+//!       Into<YourField1>, Into<YourField2>..., returning a [Ok] with your struct if every field could
+//!       successfully be turned to your field, in case not, it will return [Err] with an enum telling
+//!       which field couldn't get initialized and the Error why it didn't, see examples below for this.
+//!       <br> Fields marked with ```#[into]``` take any ```Into<YourField>``` and fields marked with
+//!       ```#[exact]``` take their exact type, neither getting a variant on the error enum, while
+//!       unmarked fields, just like those marked with ```#[try_from]```, are converted through
+//!       TryFrom, so the From, Into, Builder and TypestateBuilder patterns can't receive fields marked
+//!       with ```#[try_from]```. <br> The types fields are received
+//!       as might be declared, like ```fields(name: &str, age: u16)``` or ```#[try_from(u16)]```, in
+//!       which case they are converted through TryFrom and their variants hold the conversion's
+//!       concrete error, like ```AgeError(<u8 as TryFrom<u16>>::Error)```, so when every field
+//!       declares it, the error enum has no generic parameters and can be named in signatures. <br>
+//!       As these variants name the declared types with ```'static``` lifetimes, types hiding a
+//!       lifetime must write it, like ```#[try_from(std::str::Chars<'_>)]``` rather than
+//!       ```#[try_from(std::str::Chars)]```, while references like ```&str``` may keep it elided.
+//!     - When using the Into pattern, the function receives types that implement
+//!       Into<YourField1>, Into<YourField2>..., like ```fn new(name: impl Into<String>, age: impl
+//!       Into<u8>)```, letting you call ```CharacterInfo::new("Jorge", 23_u8)```, this pattern requires
+//!       the ```named``` attribute. <br> If some fields are marked with ```#[into]```, only those
+//!       take an ```impl Into<...>``` while the rest take their exact type, which keeps type
+//!       inference working for parameters like numeric literals.
+//!     - When using the Builder pattern, the function (named ```builder``` unless ```named``` is
+//!       given) returns a builder named ```{YourStruct}Builder``` with a setter for each field, like
+//!       ```CharacterInfo::builder().name("Jorge".to_string()).age(23).build()```, where ```build```
+//!       initializes the rest of fields just like the other patterns do, returning [Err] with a
+//!       variant like ```MissingAge``` when a field that hasn't a default wasn't set.
+//!     - When using the TypestateBuilder pattern, you get a builder just like with the Builder
+//!       pattern, but whether each field has been set is tracked in the builder's type, so
+//!       ```build``` only exists once every field in ```fields``` has been set, returning your struct
+//!       directly, while fields with defaults can be optionally set.
+//!     - When using the FromStr pattern, the function (named ```parse``` unless ```named``` is given)
+//!       receives a ```&str``` for each field and parses them through FromStr, like
+//!       ```CharacterInfo::parse("Jorge", "23")```, along with a ```from_strs``` function taking them
+//!       as a row, like ```CharacterInfo::from_strs(&["Jorge", "23"])```, both returning [Err] with an
+//!       enum telling which value couldn't be parsed, like ```AgeError(ParseIntError)```, or
+//!       ```RowLength { expected, found }``` when the row doesn't hold a value for each field.
 //!
 //! - fields (default: All fields not included in the '```defaults```' attribute): Name of the
-//! fields you want to create your constructor for, for example: ```fields(age, name)``` could
-//! result in a function like: ```fn new(age: u8, name: String) -> CharacterInfo```.
+//!   fields you want to create your constructor for, for example: ```fields(age, name)``` could
+//!   result in a function like: ```fn new(age: u8, name: String) -> CharacterInfo```. <br> On tuple
+//!   structs fields are given by their index, as in ```fields(0, 2)```, and their parameters are
+//!   named ```field_{index}```, like ```fn new(field_0: f64, field_2: Unit) -> Meters```.
 //!
 //! - defaults: Tells how to initialize fields not covered in the ```fields``` attribute, for
-//! example ```defaults(years_studied(4))```. <br>If a field isn't either on the ```fields``` or
-//! ```defaults``` attributes, it would count as it was initialized through [Default::default], this
-//! means, the ```times_appeared``` field that hasn't been covered will be init as 0 (since
-//! u8::default() is 0).
-//!
-//! - error_enum_named (Only for the TryFrom pattern): Specifies the name for the enum error that
-//! it's returned the TryFrom function fails.
-//!
-//! - error_enum_metadata (Only for the TryFrom pattern): Declares the metadata for the enum error
-//! that it's returned the TryFrom function fails, you will most likely want to write
-//! ```error_enum_metadata(#[derive(Debug)])``` in there.
-//! <br><br>
+//!   example ```defaults(years_studied(4))```, or ```defaults(1(4))``` for the positional field at
+//!   index 1. <br>If a field isn't either on the ```fields``` or
+//!   ```defaults``` attributes, it would count as it was initialized through [Default::default], this
+//!   means, the ```times_appeared``` field that hasn't been covered will be init as 0 (since
+//!   u8::default() is 0), unless the field has a ```#[no_from(value)]``` attribute, in which case
+//!   that value is used. <br>Defaults are initialized in the order fields are declared, so they can
+//!   use the constructor's parameters and the fields declared before them, like
+//!   ```defaults(display_name(format!("{name} ({age})")))``` or ```#[no_from(name.len())]```, while
+//!   using a field declared after them is a compile error. Values bound inside a default by a
+//!   closure's parameters, a ```let``` or a ```for``` may be named like later fields, while other
+//!   bindings, like those of match arms, count as using the field they are named like.
+//!
+//! - convert: Functions building fields out of another type, so the constructor takes that type
+//!   instead, like ```convert(timeout(with = Duration::from_millis, from = u64))```, which can also
+//!   be declared on the field as ```#[convert(with = Duration::from_millis, from = u64)]```. <br>If
+//!   the function returns a [Result], its error type must be given through ```error = ErrorType```,
+//!   otherwise the function is reported as returning a [Result] where the field's type was expected.
+//!   These converters are only applied by the TryFrom pattern, where the error is returned as the
+//!   field's variant of the error enum, like ```TimeoutError(ErrorType)```, while the FromStr pattern
+//!   parses fields declaring them through ```#[convert(...)]``` as they are, and the From, Into,
+//!   Builder and TypestateBuilder patterns, as well as the From derive, fail to compile when
+//!   receiving them, just like when receiving fields marked with ```#[try_from]```.
+//!
+//! - from_unit (a flag, it takes no value): When there is no ```named``` attribute and there are no
+//!   fields left to receive, [From] is only implemented for ```()``` if this flag is given, while
+//!   with a ```named``` attribute you simply get a constructor with no parameters, like
+//!   ```fn new() -> Self```.
+//!
+//! - parts (a flag, it takes no value): Splits your struct back into the values its constructor
+//!   receives, giving it the functions ```into_parts(self) -> (String, u8)``` and
+//!   ```as_parts(&self) -> (&String, &u8)```, and implementing ```From<YourStruct>``` for the tuple of
+//!   said values, where fields not received, like those given in ```defaults```, are left out.<br>
+//!   Only one constructor of your struct can ask for it, and [From] isn't implemented when there's a
+//!   single value whose type has an uncovered generic parameter, like ```T```, ```&'a T``` or
+//!   ```Box<T>```, as Rust doesn't allow it.<br>
+//!   As ```into_parts``` moves the values out of your struct, it can't implement [Drop].
+//!
+//! - validate: Function checking your struct once it's built along with the type of its error, like
+//!   ```validate(check_age, AgeError)``` for a ```fn check_age(character: &CharacterInfo) ->
+//!   Result<(), AgeError>```, when it returns [Err] the constructor fails with said error:
+//!     - With the From and Into patterns the constructor returns ```Result<YourStruct, AgeError>```,
+//!       where if there's no ```named``` attribute, [TryFrom] is implemented instead of [From].
+//!     - With the TryFrom, Builder and FromStr patterns the error enum gets a ```ValidationError(AgeError)```
+//!       variant, as this enum doesn't take your struct's generics, ```AgeError``` can't use them.
+//!     - With the TypestateBuilder pattern ```build``` returns ```Result<YourStruct, AgeError>```.
+//!
+//!   Besides, with the TryFrom and FromStr patterns, fields might declare checks their converted values must pass
+//!   through the ```#[validate(...)]``` attribute, each failing with its own unit variant of the error
+//!   enum. The From, Into, Builder and TypestateBuilder patterns, as well as the From derive, have no
+//!   error to report them through, so rather than skipping the checks they fail to compile when
+//!   receiving a field with checks, leave it out of their ```fields``` to give it its default instead:
+//!     - ```#[validate(range(0..150))]```: The value must be in the range, or it fails with
+//!       ```AgeOutOfRange```.
+//!     - ```#[validate(non_empty)]```: The value's ```is_empty()``` must be false, or it fails with
+//!       ```NameEmpty```.
+//!     - ```#[validate(len(1..=32))]```: The value's ```len()``` must be in the range, or it fails
+//!       with ```NameInvalidLength```.
+//!     - ```#[validate(with = path::to::predicate)]```: The predicate, taking a reference to the
+//!       value, must return true, or it fails with ```NameRejected```.
+//!
+//! - const (a flag, it takes no value, only for named constructors of the From pattern): Makes the
+//!   constructor a ```const fn```, letting you use it on consts and statics, like
+//!   ```const ROOT: CharacterInfo = CharacterInfo::new("root", 0);```. <br> Since
+//!   [Default::default] can't be called on const functions, every field that isn't received must be
+//!   given a const default through ```defaults``` or ```#[no_from(value)]```, or it fails to compile
+//!   telling which field lacks one.
+//!
+//! - doc: Summary documenting the constructor function, like ```doc("Creates a playable character.")```.
+//!   <br> Every generated item is documented, where constructor functions list their parameters along
+//!   with the docs of their fields and the values the rest of fields are initialized as, and error
+//!   enums document each of their variants, so they can be used on crates denying missing docs, this
+//!   attribute replaces the constructor's generated summary.
+//!
+//! - vis (default: pub): Visibility of the constructor function, and for builders, of the builder and
+//!   its functions, like ```vis(pub(crate))```, where ```vis(inherit)``` takes your struct's
+//!   visibility and ```vis()``` makes them private.
+//!
+//! - error_enum_vis (default: the one given in ```vis```): Visibility of the error enum, which takes
+//!   the same values as ```vis```.
+//!
+//! - fn_attrs: Attributes written on the constructor function, like
+//!   ```fn_attrs(#[must_use] #[inline])```.
+//!
+//! - impl_attrs: Attributes written on the impl blocks holding the constructor, like
+//!   ```impl_attrs(#[allow(clippy::too_many_arguments)])```.<br>
+//!   If your struct has a ```#[cfg(...)]``` attribute, it's also copied onto every generated item, so
+//!   they are only compiled along with your struct.
+//!
+//! - from_strs_named (Only for the FromStr pattern): Specifies the name for the function taking a
+//!   row, by default, it's ```from_strs```.
+//!
+//! - builder_named (Only for the Builder and TypestateBuilder patterns): Specifies the name for the
+//!   builder, by default,
+//!   it's ```{YourStruct}Builder```.
+//!
+//! - error_enum_named (Only for the TryFrom, Builder and FromStr patterns): Specifies the name for the enum
+//!   error that it's returned the TryFrom function fails, for builders it's named
+//!   ```{YourBuilder}Error``` by default.
+//!
+//! - error_enum_metadata (Only for the TryFrom, Builder and FromStr patterns): Declares the metadata for the enum error
+//!   that it's returned the TryFrom function fails, you will most likely want to write
+//!   ```error_enum_metadata(#[derive(Debug)])``` in there.
+//!
+//! - error_variants_named (Only for the TryFrom and FromStr patterns): Template naming the variants
+//!   holding the error of each field, where ```{Field}``` stands for the field's name, like
+//!   ```error_variants_named("Invalid{Field}")```, by default, it's ```{Field}Error```, while a field
+//!   marked like ```#[error_variant(InvalidAge)]``` gives its variant's name directly, which can't
+//!   repeat the name of another variant, like those of checks, ```ValidationError```, or FromStr's
+//!   ```RowLength```.<br>
+//!   These error enums have ```field_name()``` and ```field_index()``` functions telling the name of
+//!   the field a variant is about and its position among the constructor's parameters, where the
+//!   validator's error is about ```validation``` and FromStr's ```RowLength``` is about ```row```.
+//!
+//! - error_enum (Only for the TryFrom pattern): Shares an error enum between several constructors,
+//!   where ```error_enum(define = CharacterInfoError)``` declares it on one of them, and
+//!   ```error_enum(use = CharacterInfoError)``` uses it on the rest, along with the TryFrom derive's
+//!   ```#[error_enum(use = CharacterInfoError)]```.<br>
+//!   This enum has no generic parameters, so every constructor returns the same type, holding a
+//!   variant for every field declaring the type it's received as, like ```#[try_from(u16)]```, or a
+//!   converter that may fail, like ```#[convert(..., error = ...)]```, even for fields marked with
+//!   ```#[no_from]```, along with the checks of every field and, if the constructor defining it has a
+//!   validator, ```ValidationError```, holding the error the validators of every constructor sharing it
+//!   must return.<br>
+//!   Constructors converting a field whose error can't be named this way, or receiving it as another
+//!   type than the one the field declares, like ```fields(age: i64)```, are rejected, as are
+//!   ```error_enum_metadata``` and ```error_enum_impls``` on constructors using the enum rather than
+//!   defining it, while it can't be combined with ```error_enum_named```, ```error_variants_named```
+//!   or ```errors```.
+//!
+//! - error_enum_impls (a flag, it takes no value, only for the TryFrom and FromStr patterns):
+//!   Implements [Display](std::fmt::Display) and [Error](std::error::Error) for the enum error, so it
+//!   can be returned as a ```Box<dyn Error>```, where each variant tells the struct and field that
+//!   failed, like ```CharacterInfo::age: number too large to fit in target type```, and
+//!   ```source()``` returns the error of the conversion when it implements Error. <br>This requires
+//!   deriving Debug through ```error_enum_metadata```, and the conversions' errors implementing
+//!   Display, like ```String``` does, while those that also implement Error are given by ```source()```.
+//!
+//! - errors (only for the TryFrom pattern): Setting ```errors(collect)``` converts and checks every
+//!   field even after one of them fails, returning a ```{YourErrorEnum}s``` struct instead of the
+//!   error enum, like ```CharacterInfoNewErrors```, holding an ```Option``` with the first error found
+//!   on each field that might fail, and on ```validation``` for the validator, where ```iter()```
+//!   goes through the errors found along with the names of their fields when the error enum derives
+//!   Debug.<br>
+//!   Along with ```error_enum_impls```, said struct displays every error found, separated by ```; ```.
+//!   <br>Setting ```errors(return_inputs)``` returns a ```{YourErrorEnum}WithInputs``` struct instead,
+//!   holding the error on ```error``` along with the values received, so values that can't be cloned,
+//!   like sockets or buffers, aren't lost, where the values before the one that failed are held
+//!   already converted on fields like ```name```, and the values after it on fields like
+//!   ```age_input```, as received, the value that failed is consumed by its conversion, and every
+//!   value is held converted when the validator fails, which takes them back from the struct, so it
+//!   can't implement Drop.
+//!   <br>Both structs only take the standard derives of ```error_enum_metadata```, like ```Debug``` or
+//!   ```PartialEq```, as other metadata, like ```#[repr(u8)]```, might only apply to enums.
+//!   <br><br>
 //!
 //! ## 2.1 Example 1: Empty constructor
 //!
@@ -149,6 +336,70 @@
 //! }
 //! ```
 //!
+//! ## 2.4 Example 4: A typestate builder
+//!
+//! The following example creates a function named ```builder()``` returning a builder where
+//! ```name``` and ```age``` must be set before calling ```build```, while
+//! ```years_studied``` might be set or left as 4.
+//!
+//! ``` rust
+//! let character = CharacterInfo::builder().age(23).name("Jorge".to_string()).build();
+//! let expected_character = CharacterInfo { name: "Jorge".to_string(), age: 23, years_studied: 4};
+//! assert_eq!(character, expected_character);
+//!
+//! #[derive(Debug, PartialEq)]
+//! #[derive_constructors::constructor(pattern(TypestateBuilder), fields(name, age), defaults(years_studied(4)))]
+//! struct CharacterInfo{
+//!     name: String,
+//!     age: u8,
+//!     years_studied: u8,
+//! }
+//! ```
+//!
+//! Forgetting to set a field means there is no ```build``` function to call:
+//!
+//! ``` compile_fail
+//! let character = CharacterInfo::builder().name("Jorge".to_string()).build();
+//!
+//! #[derive_constructors::constructor(pattern(TypestateBuilder), fields(name, age))]
+//! struct CharacterInfo{
+//!     name: String,
+//!     age: u8,
+//! }
+//! ```
+//!
+//! ## 2.5 Example 5: Several constructors at once
+//!
+//! > Ref: [derive_constructors_proc::constructors]
+//!
+//! The [constructors] attribute declares a constructor for each of its entries, named after the
+//! entry and taking the same attributes as the [constructor] attribute, while the attributes
+//! written outside the entries are shared by all of them, where entries named ```auto``` are named
+//! just like with ```named(auto)```. <br><br>
+//! The following example creates the functions ```new(name: String, age: u8)```,
+//! ```with_id(id: u32)``` and ```try_new(name: T, age: U)```, where ```defaults``` are merged, so
+//! ```with_id``` initializes ```name``` as "Unknown", while ```new``` and ```try_new``` ignore the
+//! shared default of ```name```, as they receive it as a parameter.
+//!
+//! ``` rust
+//! #[derive(Debug, PartialEq)]
+//! #[derive_constructors::constructors(
+//!     new(fields(name, age)),
+//!     with_id(fields(id), defaults(age(0))),
+//!     try_new(pattern(TryFrom), fields(name, age), error_enum_named(TryNewError)),
+//!     defaults(id(1), name("Unknown".to_string())),
+//!     error_enum_metadata(#[derive(Debug)]),
+//! )]
+//! struct CharacterInfo{
+//!     id: u32,
+//!     name: String,
+//!     age: u8,
+//! }
+//!
+//! assert_eq!(CharacterInfo::new("Jorge".to_string(), 23), CharacterInfo { id: 1, name: "Jorge".to_string(), age: 23 });
+//! assert_eq!(CharacterInfo::with_id(7), CharacterInfo { id: 7, name: "Unknown".to_string(), age: 0 });
+//! assert_eq!(CharacterInfo::try_new("Jorge", 23_u16).unwrap(), CharacterInfo { id: 1, name: "Jorge".to_string(), age: 23 });
+//! ```
 //! ## 3 The Derive macro for enums: From
 //!
 //! > Ref: [derive_constructors_proc::From]
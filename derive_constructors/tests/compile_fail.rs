@@ -0,0 +1,7 @@
+// Constructors the macros reject fail to compile telling why, each case is a file under 'tests/ui'
+// along with the message it fails with.
+#[test]
+fn rejected_constructors() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -2,6 +2,7 @@
 
 use std::io;
 use std::convert::Infallible;
+use std::marker::PhantomData;
 use std::net::{AddrParseError, IpAddr};
 use std::num::TryFromIntError;
 
@@ -150,3 +151,1143 @@ fn test() {
     };
     assert!(my_two_errors_is_two_errors);
 }
+
+// Generic structs keep their generics, bounds and where clauses on every generated impl, the
+// 'counters' field is initialized through Default::default(), so a '[u8; N]: Default' bound is added,
+// while 'marker' is a PhantomData, which is never asked as a parameter.
+#[constructor(named(new), fields(inner, label))]
+#[constructor(
+named(try_new),
+pattern(TryFrom),
+fields(inner, label),
+error_enum_metadata(# [derive(Debug, PartialEq)]),
+)]
+#[derive(From, Debug, PartialEq)]
+pub struct Wrapper<'a, T, const N: usize> where T: Clone {
+    inner: T,
+    label: &'a str,
+    #[no_from]
+    counters: [u8; N],
+    marker: PhantomData<T>,
+}
+
+// Only core's PhantomData is skipped, a type of another module named the same is still a parameter.
+mod tagging {
+    #[derive(Debug, PartialEq)]
+    pub struct PhantomData(pub &'static str);
+}
+
+#[derive(From, Debug, PartialEq)]
+pub struct Tagged {
+    value: u8,
+    tag: tagging::PhantomData,
+}
+
+#[derive(TryFrom, Debug, PartialEq)]
+#[enum_error_meta(# [derive(Debug, PartialEq)])]
+pub struct Pair<A, B: Copy> {
+    first: A,
+    second: B,
+}
+
+#[test]
+fn generic_structs() {
+    let expected = Wrapper { inner: 23_u8, label: "age", counters: [0; 2], marker: PhantomData };
+    assert_eq!(Wrapper::from((23_u8, "age")), expected);
+    assert_eq!(Wrapper::new(23_u8, "age"), expected);
+    assert_eq!(Wrapper::<u8, 2>::try_new(23_u16, "age"), Ok(expected));
+    assert_eq!(
+        Wrapper::<u8, 2>::try_new(2003_u16, "age"),
+        Err(WrapperTryNewError::InnerError(u8::try_from(2003_u16).unwrap_err()))
+    );
+
+    assert_eq!(Tagged::from((1, tagging::PhantomData("id"))), Tagged { value: 1, tag: tagging::PhantomData("id") });
+
+    assert_eq!(Pair::<u8, i8>::try_from((23_u16, 5_i64)), Ok(Pair { first: 23, second: 5 }));
+    assert_eq!(
+        Pair::<u8, i8>::try_from((23_u16, 500_i64)),
+        Err(PairTryFromError::SecondError(i8::try_from(500_i64).unwrap_err()))
+    );
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub enum Unit {
+    #[default]
+    Meters,
+    Feet,
+}
+
+// Positional fields are named 'field_{index}' in constructors, so 'with_value' below is
+// 'with_value(field_0: f64)', and error variants are named after them, like 'Field0Error'.
+#[constructor(named(with_value), fields(0))]
+#[constructor(named(with_unit), fields(1), defaults(0(1.0)))]
+#[constructor(
+named(try_new),
+pattern(TryFrom),
+fields(0, 1),
+error_enum_metadata(# [derive(Debug, PartialEq)]),
+)]
+#[derive(From, Debug, PartialEq)]
+pub struct Meters(f64, #[no_from] Unit);
+
+#[derive(TryFrom, Debug, PartialEq)]
+#[enum_error_meta(# [derive(Debug, PartialEq)])]
+pub struct Rgb(u8, u8, u8);
+
+#[test]
+fn tuple_structs() {
+    assert_eq!(Meters::from(2.5), Meters(2.5, Unit::Meters));
+    assert_eq!(Meters::with_value(2.5), Meters(2.5, Unit::Meters));
+    assert_eq!(Meters::with_unit(Unit::Feet), Meters(1.0, Unit::Feet));
+    assert_eq!(Meters::try_new(2.5_f32, Unit::Feet), Ok(Meters(2.5, Unit::Feet)));
+
+    assert_eq!(Rgb::try_from((255_u16, 128_i32, 0_u64)), Ok(Rgb(255, 128, 0)));
+    assert_eq!(
+        Rgb::try_from((255_u16, 256_i32, 0_u64)),
+        Err(RgbTryFromError::Field1Error(u8::try_from(256_i32).unwrap_err()))
+    );
+}
+
+// Unit structs and structs whose every field gets a default only get constructors with no
+// parameters, and 'From<()>' is only implemented when asked for through 'from_unit'.
+#[constructor(named(new))]
+#[constructor(from_unit)]
+#[derive(Debug, PartialEq)]
+pub struct Marker;
+
+// Without any of the From or TryFrom derives, the '#[no_from]' attributes are removed once the last
+// constructor attribute has read them.
+#[constructor(named(new))]
+#[constructor(named(try_new), pattern(TryFrom), error_enum_metadata(# [derive(Debug)]))]
+#[derive(Debug, PartialEq)]
+pub struct Counters {
+    #[no_from(1)]
+    starts_at: u32,
+    #[no_from]
+    current: u32,
+}
+
+#[derive(From, Debug, PartialEq)]
+#[from_unit]
+pub struct Empty {}
+
+// Unit variants of enums still implement 'From<()>' without asking for it.
+#[derive(From, Debug, PartialEq)]
+pub enum Slot {
+    Empty,
+    Full(u8),
+}
+
+#[test]
+fn zero_parameter_constructors() {
+    assert_eq!(Marker::new(), Marker);
+    assert_eq!(Marker::from(()), Marker);
+    assert_eq!(Counters::new(), Counters { starts_at: 1, current: 0 });
+    assert_eq!(Counters::try_new().unwrap(), Counters { starts_at: 1, current: 0 });
+    assert_eq!(Empty::from(()), Empty {});
+    assert_eq!(Slot::from(()), Slot::Empty);
+    assert_eq!(Slot::from(3), Slot::Full(3));
+}
+
+// The Into pattern takes 'impl Into<FieldType>' parameters, unless some fields are marked with
+// '#[into]', where only those do, so 'Player::new' is 'new(name: impl Into<String>, age: u8)'.
+#[constructor(named(new), pattern(Into), fields(name, age))]
+#[derive(Debug, PartialEq)]
+pub struct Player {
+    #[into]
+    name: String,
+    age: u8,
+    nickname: Option<String>,
+}
+
+#[constructor(named(new), pattern(Into))]
+#[derive(Debug, PartialEq)]
+pub struct Team {
+    name: String,
+    city: Option<String>,
+}
+
+#[test]
+fn into_pattern() {
+    assert_eq!(Player::new("Jorge", 23), Player { name: "Jorge".to_string(), age: 23, nickname: None });
+    assert_eq!(Team::new("Team", "Madrid".to_string()), Team { name: "Team".to_string(), city: Some("Madrid".to_string()) });
+}
+
+// Builders get a setter for each field in 'fields', and 'build' applies the defaults, failing when
+// a field that has no default wasn't set.
+#[constructor(
+pattern(Builder),
+fields(host, port, retries),
+defaults(timeout_secs(30)),
+error_enum_metadata(# [derive(Debug, PartialEq)]),
+)]
+#[derive(Debug, PartialEq)]
+pub struct ServerConfig<Tag> {
+    #[into]
+    host: String,
+    port: u16,
+    retries: u8,
+    timeout_secs: u32,
+    #[no_from(vec!["admin"])]
+    users: Vec<&'static str>,
+    tag: Option<Tag>,
+}
+
+#[test]
+fn builder_pattern() {
+    let config = ServerConfig::<()>::builder().port(8080).host("localhost").retries(3).build();
+    assert_eq!(config, Ok(ServerConfig {
+        host: "localhost".to_string(),
+        port: 8080,
+        retries: 3,
+        timeout_secs: 30,
+        users: vec!["admin"],
+        tag: None,
+    }));
+    assert_eq!(
+        ServerConfig::<()>::builder().host("localhost").retries(3).build(),
+        Err(ServerConfigBuilderError::MissingPort)
+    );
+}
+
+// The typestate builder only has a 'build' function once 'name' and 'age' are set, while the
+// defaulted fields can be optionally set.
+#[constructor(
+pattern(TypestateBuilder),
+named(typed_builder),
+builder_named(CharacterCardBuilder),
+fields(name, age),
+defaults(title("Unknown")),
+)]
+#[derive(Debug, PartialEq)]
+pub struct CharacterCard<'a> {
+    #[into]
+    name: String,
+    age: u8,
+    title: &'a str,
+    #[no_from]
+    appeared_in_movies: u32,
+}
+
+#[test]
+fn typestate_builder() {
+    let card = CharacterCard::typed_builder().age(23).name("Jorge").build();
+    assert_eq!(card, CharacterCard { name: "Jorge".to_string(), age: 23, title: "Unknown", appeared_in_movies: 0 });
+
+    let card = CharacterCard::typed_builder().title("Developer").name("Jorge").appeared_in_movies(2).age(23).build();
+    assert_eq!(card, CharacterCard { name: "Jorge".to_string(), age: 23, title: "Developer", appeared_in_movies: 2 });
+}
+
+// The type parameters tracking whether a field is set don't collide with the struct's generics,
+// even when one is named like them.
+#[constructor(pattern(TypestateBuilder), fields(value, tag))]
+#[derive(Debug, PartialEq)]
+pub struct Labeled<ValueState> {
+    value: ValueState,
+    tag: u8,
+}
+
+#[test]
+fn typestate_builder_generics() {
+    assert_eq!(Labeled::builder().tag(1).value("one").build(), Labeled { value: "one", tag: 1 });
+}
+
+// Checks the struct once it's built, where an age over 150 is only allowed for unnamed characters,
+// the TryFrom pattern returns the validator's error as 'ValidationError', while the From pattern
+// returns it directly.
+#[derive(Debug, PartialEq)]
+pub struct ImpossibleAge(u8);
+
+fn check_age(character: &AgedCharacter) -> Result<(), ImpossibleAge> {
+    match character.name.is_empty() || character.age < 150 {
+        true => Ok(()),
+        false => Err(ImpossibleAge(character.age)),
+    }
+}
+
+#[constructor(named(new), validate(check_age, ImpossibleAge))]
+#[constructor(
+named(try_new),
+pattern(TryFrom),
+validate(check_age, ImpossibleAge),
+error_enum_named(AgedCharacterError),
+error_enum_metadata(#[derive(Debug, PartialEq)])
+)]
+#[derive(Debug, PartialEq, TryFrom)]
+#[enum_error_meta(#[derive(Debug, PartialEq)])]
+#[validate(check_age, ImpossibleAge)]
+pub struct AgedCharacter {
+    name: String,
+    age: u8,
+}
+
+#[test]
+fn struct_validation() {
+    let character = AgedCharacter { name: "Jorge".to_string(), age: 23 };
+    assert_eq!(AgedCharacter::new("Jorge".to_string(), 23), Ok(character));
+    assert_eq!(AgedCharacter::new("Jorge".to_string(), 200), Err(ImpossibleAge(200)));
+    assert_eq!(AgedCharacter::new(String::new(), 200), Ok(AgedCharacter { name: String::new(), age: 200 }));
+
+    assert_eq!(AgedCharacter::try_new("Jorge", 200_u16), Err(AgedCharacterError::ValidationError(ImpossibleAge(200))));
+    assert!(matches!(AgedCharacter::try_new("Jorge", 2000_u16), Err(AgedCharacterError::AgeError(_))));
+    assert_eq!(AgedCharacter::try_from(("Jorge", 200_u16)), Err(AgedCharacterTryFromError::ValidationError(ImpossibleAge(200))));
+}
+
+// Checks each field once converted, where every check that fails is reported through its own
+// variant of the error enum, like 'AgeOutOfRange' or 'NicknameRejected'.
+fn is_lowercase(nickname: &String) -> bool {
+    nickname.chars().all(char::is_lowercase)
+}
+
+#[constructor(
+named(try_new),
+pattern(TryFrom),
+error_enum_named(ValidatedCharacterError),
+error_enum_metadata(#[derive(Debug, PartialEq)])
+)]
+#[derive(Debug, PartialEq, TryFrom)]
+#[enum_error_meta(#[derive(Debug, PartialEq)])]
+pub struct ValidatedCharacter {
+    #[validate(non_empty)]
+    name: String,
+    #[validate(range(0..150))]
+    age: u8,
+    #[validate(len(3..=12), with = is_lowercase)]
+    nickname: String,
+}
+
+#[test]
+fn field_validations() {
+    let character = ValidatedCharacter { name: "Jorge".to_string(), age: 23, nickname: "jorge".to_string() };
+    assert_eq!(ValidatedCharacter::try_new("Jorge", 23_u16, "jorge"), Ok(character));
+    assert_eq!(ValidatedCharacter::try_new("", 23_u16, "jorge"), Err(ValidatedCharacterError::NameEmpty));
+    assert_eq!(ValidatedCharacter::try_new("Jorge", 200_u16, "jorge"), Err(ValidatedCharacterError::AgeOutOfRange));
+    assert_eq!(ValidatedCharacter::try_new("Jorge", 23_u16, "jo"), Err(ValidatedCharacterError::NicknameInvalidLength));
+    assert_eq!(ValidatedCharacter::try_new("Jorge", 23_u16, "Jorge"), Err(ValidatedCharacterError::NicknameRejected));
+    assert!(matches!(ValidatedCharacter::try_new("Jorge", 2000_u16, "jorge"), Err(ValidatedCharacterError::AgeError(_))));
+
+    assert_eq!(ValidatedCharacter::try_from(("Jorge", 200_u16, "jorge")), Err(ValidatedCharacterTryFromError::AgeOutOfRange));
+}
+
+// Defaults are initialized in the order fields are declared, so they can use the constructor's
+// parameters and the defaults of the fields declared before them, where a macro or a path named like
+// a later field, as 'vec!' is for 'vec', doesn't count as using it.
+#[constructor(
+named(new),
+fields(name, age),
+defaults(display_name(format!("{name} ({age})")), display_name_len(display_name.len())),
+)]
+#[derive(Debug, PartialEq, From)]
+pub struct DisplayedCharacter {
+    name: String,
+    age: u8,
+    #[no_from(name.to_uppercase())]
+    display_name: String,
+    #[no_from(display_name.len())]
+    display_name_len: usize,
+    #[no_from(vec![display_name_len])]
+    lengths: Vec<usize>,
+    #[no_from(lengths.len())]
+    vec: usize,
+}
+
+#[test]
+fn defaults_using_parameters_and_fields() {
+    let expected = DisplayedCharacter {
+        name: "Jorge".to_string(),
+        age: 23,
+        display_name: "Jorge (23)".to_string(),
+        display_name_len: 10,
+        lengths: vec![10],
+        vec: 1,
+    };
+    assert_eq!(DisplayedCharacter::new("Jorge".to_string(), 23), expected);
+
+    let expected = DisplayedCharacter {
+        name: "Jorge".to_string(),
+        age: 23,
+        display_name: "JORGE".to_string(),
+        display_name_len: 5,
+        lengths: vec![5],
+        vec: 1,
+    };
+    assert_eq!(DisplayedCharacter::from(("Jorge".to_string(), 23)), expected);
+}
+
+// Values bound inside a default, like a closure's parameters or a 'let', don't count as using the
+// later fields named like them.
+#[derive(Debug, PartialEq, From)]
+pub struct ScoredCharacter {
+    scores: Vec<u8>,
+    #[no_from(scores.iter().map(|best| u32::from(*best)).max().unwrap_or_default())]
+    highest: u32,
+    #[no_from({ let best = scores.len(); best * 2 })]
+    doubled_len: usize,
+    #[no_from(scores.iter().copied().max())]
+    best: Option<u8>,
+}
+
+#[test]
+fn defaults_binding_names_of_later_fields() {
+    let expected = ScoredCharacter { scores: vec![3, 7], highest: 7, doubled_len: 4, best: Some(7) };
+    assert_eq!(ScoredCharacter::from(vec![3, 7]), expected);
+}
+
+// Declares the pair of constructors of 'CharacterInfo' in a single attribute, where the defaults and
+// the error enum's metadata are written once and shared by both entries.
+#[constructors(
+with_age_and_name(fields(age, name)),
+try_with_age_and_name(pattern(TryFrom), fields(age, name), error_enum_named(GetWithAgeAndNameTwinError)),
+with_id(fields(id), defaults(name("Anonymous"))),
+defaults(
+id("Jorge".to_string()),
+family_names(vec ! ["Rico", "Vivas"])
+),
+error_enum_metadata(# [derive(Debug, PartialEq)]),
+)]
+#[derive(Debug, PartialEq)]
+pub struct CharacterInfoTwin {
+    age: u8,
+    name: &'static str,
+    id: String,
+    family_names: Vec<&'static str>,
+    #[no_from]
+    appeared_in_movies: u8,
+}
+
+#[test]
+fn several_constructors() {
+    let expected = CharacterInfoTwin {
+        age: 23,
+        name: "Jorge",
+        id: "Jorge".to_string(),
+        family_names: vec!["Rico", "Vivas"],
+        appeared_in_movies: 0,
+    };
+    assert_eq!(CharacterInfoTwin::with_age_and_name(23, "Jorge"), expected);
+    assert_eq!(CharacterInfoTwin::try_with_age_and_name(23_u16, "Jorge"), Ok(expected));
+    assert!(matches!(CharacterInfoTwin::try_with_age_and_name(2003_u16, "Jorge"), Err(GetWithAgeAndNameTwinError::AgeError(_))));
+
+    let expected = CharacterInfoTwin {
+        age: 0,
+        name: "Anonymous",
+        id: "JRV".to_string(),
+        family_names: vec!["Rico", "Vivas"],
+        appeared_in_movies: 0,
+    };
+    assert_eq!(CharacterInfoTwin::with_id("JRV".to_string()), expected);
+}
+
+// Names the constructors after the fields they receive, like 'with_name_and_age', where the TryFrom
+// pattern's constructor and error enum follow its 'try_with_*' name.
+#[constructor(named(auto), fields(name, age))]
+#[constructor(named(auto), fields(name, age), pattern(TryFrom), error_enum_metadata(#[derive(Debug, PartialEq)]))]
+#[constructor(named(auto), fields(id), named_prefix(from), named_joiner(plus))]
+#[constructor(named(auto), fields(id, name, age), named_prefix(from), named_joiner(plus))]
+#[constructors(auto(fields(age)), auto(fields(id, age), pattern(TryFrom)), named_prefix(having))]
+#[derive(Debug, PartialEq)]
+pub struct AutoNamedCharacter {
+    id: u32,
+    name: String,
+    age: u8,
+}
+
+#[test]
+fn auto_named_constructors() {
+    let expected = AutoNamedCharacter { id: 0, name: "Jorge".to_string(), age: 23 };
+    assert_eq!(AutoNamedCharacter::with_name_and_age("Jorge".to_string(), 23), expected);
+    assert_eq!(AutoNamedCharacter::try_with_name_and_age("Jorge", 23_u16), Ok(expected));
+    assert!(matches!(AutoNamedCharacter::try_with_name_and_age("Jorge", 2003_u16), Err(AutoNamedCharacterTryWithNameAndAgeError::AgeError(_))));
+
+    assert_eq!(AutoNamedCharacter::from_id(7), AutoNamedCharacter { id: 7, name: String::new(), age: 0 });
+    assert_eq!(AutoNamedCharacter::from_id_plus_name_plus_age(7, "Jorge".to_string(), 23), AutoNamedCharacter { id: 7, name: "Jorge".to_string(), age: 23 });
+
+    assert_eq!(AutoNamedCharacter::having_age(23), AutoNamedCharacter { id: 0, name: String::new(), age: 23 });
+    assert!(AutoNamedCharacter::try_having_id_and_age(7_u64, 23_u16).is_ok());
+}
+
+// Constructors of a struct private to its module take its visibility through 'vis(inherit)', while
+// the error enums can be given their own visibility.
+mod private_character {
+    use super::*;
+
+    #[constructor(named(new), vis(inherit))]
+    #[constructor(named(try_new), pattern(TryFrom), vis(pub(super)), error_enum_vis(pub(crate)), error_enum_named(TryNewPrivateError))]
+    #[constructor(pattern(Builder), vis(pub(super)), error_enum_metadata(#[derive(Debug)]))]
+    #[derive(Debug, PartialEq, TryFrom)]
+    #[error_enum_vis(pub(super))]
+    pub(super) struct PrivateCharacter {
+        name: String,
+        age: u8,
+    }
+
+    #[test]
+    fn private_constructor() {
+        assert_eq!(PrivateCharacter::new("Jorge".to_string(), 23), PrivateCharacter { name: "Jorge".to_string(), age: 23 });
+    }
+}
+
+#[test]
+fn constructors_visibility() {
+    use private_character::PrivateCharacter;
+    let character = PrivateCharacter::try_new("Jorge", 23_u16).ok().unwrap();
+    assert_eq!(PrivateCharacter::try_from(("Jorge", 23_u16)).ok(), Some(character));
+    assert!(matches!(PrivateCharacter::try_new("Jorge", 2003_u16), Err(private_character::TryNewPrivateError::AgeError(_))));
+    assert!(matches!(PrivateCharacter::builder().build(), Err(private_character::PrivateCharacterBuilderError::MissingName)));
+    assert!(matches!(PrivateCharacter::try_from(("Jorge", 2003_u16)), Err(private_character::PrivateCharacterTryFromError::AgeError(_))));
+}
+
+// Constructors that are const functions, usable on consts and statics, as long as every field that
+// isn't received is given a const default.
+#[constructor(named(new), const, fields(name, level), defaults(retries(3)))]
+#[derive(Debug, PartialEq)]
+pub struct LookupEntry {
+    name: &'static str,
+    level: u8,
+    retries: u8,
+    #[no_from(None)]
+    fallback: Option<&'static str>,
+    marker: PhantomData<u8>,
+}
+
+const DEFAULT_ENTRY: LookupEntry = LookupEntry::new("root", 1);
+
+#[test]
+fn const_constructors() {
+    assert_eq!(DEFAULT_ENTRY, LookupEntry { name: "root", level: 1, retries: 3, fallback: None, marker: PhantomData });
+}
+
+// Every generated item is documented, so they can be used on crates denying missing docs.
+#[deny(missing_docs)]
+pub mod documented {
+    //! Documented constructors.
+    use super::*;
+
+    /// Character with every constructor documented.
+    #[constructor(named(new), fields(name, age), defaults(title("Unknown")), doc("Creates a character."))]
+    #[constructor(named(try_new), pattern(TryFrom), fields(name, age, nickname), validate(check_documented, u8))]
+    #[constructor(pattern(Builder), named(builder), fields(name, age))]
+    #[constructor(pattern(TypestateBuilder), named(typed_builder), fields(name, age), builder_named(DocumentedTypedBuilder))]
+    #[constructor(pattern(FromStr), named(parse), fields(name, age), error_enum_named(DocumentedParseError))]
+    #[derive(TryFrom)]
+    pub struct DocumentedCharacter {
+        /// Name of the character.
+        pub name: String,
+        /// Age of the character.
+        pub age: u8,
+        /// Title of the character.
+        pub title: &'static str,
+        /// Nickname of the character.
+        #[validate(non_empty)]
+        pub nickname: String,
+    }
+
+    fn check_documented(character: &DocumentedCharacter) -> Result<(), u8> {
+        Ok(())
+    }
+}
+
+#[test]
+fn documented_constructors() {
+    use documented::DocumentedCharacter;
+    assert_eq!(DocumentedCharacter::new("Jorge".to_string(), 23).title, "Unknown");
+    assert!(DocumentedCharacter::try_new("Jorge", 23_u16, "Jorgito").is_ok());
+    assert!(DocumentedCharacter::builder().name("Jorge".to_string()).age(23).build().is_ok());
+    assert_eq!(DocumentedCharacter::typed_builder().name("Jorge".to_string()).age(23).build().age, 23);
+    assert_eq!(DocumentedCharacter::parse("Jorge", "23").ok().unwrap().age, 23);
+    assert!(matches!(DocumentedCharacter::from_strs(&["Jorge"]), Err(documented::DocumentedParseError::RowLength { expected: 2, found: 1 })));
+}
+
+// Attributes given through 'fn_attrs' and 'impl_attrs' are written on the constructors and their
+// impl blocks, while the struct's '#[cfg]' is copied onto every generated item, so constructors
+// of a struct that is configured out don't get compiled either.
+#[constructor(named(new), fn_attrs(#[must_use] #[inline]), impl_attrs(#[allow(clippy::all)]))]
+#[constructor(named(try_new), pattern(TryFrom), fn_attrs(#[must_use]), impl_attrs(#[allow(unused)]))]
+#[derive(Debug, PartialEq, From)]
+#[fn_attrs(#[inline])]
+#[impl_attrs(#[allow(unused)])]
+pub struct AttributedCharacter {
+    name: String,
+    age: u8,
+}
+
+#[constructor(named(new), pattern(TryFrom))]
+#[constructor(pattern(Builder))]
+#[cfg(feature = "a_feature_that_does_not_exist")]
+pub struct ConfiguredOutCharacter {
+    name: NotDefinedAnywhere,
+}
+
+#[test]
+fn attributes_pass_through() {
+    let character = AttributedCharacter { name: "Jorge".to_string(), age: 23 };
+    assert_eq!(AttributedCharacter::new("Jorge".to_string(), 23), character);
+    assert_eq!(AttributedCharacter::from(("Jorge".to_string(), 23)), character);
+    assert!(AttributedCharacter::try_new("Jorge", 23_u16).is_ok());
+}
+
+// Only fields converted through TryFrom, which are those marked with '#[try_from]' or unmarked, get a
+// variant on the error enum, while '#[into]' fields take any 'Into<FieldType>' and '#[exact]' fields
+// take their own type.
+#[constructor(named(new), pattern(TryFrom), error_enum_metadata(#[derive(Debug, PartialEq)]))]
+#[derive(Debug, PartialEq, TryFrom)]
+#[enum_error_meta(#[derive(Debug, PartialEq)])]
+pub struct Signup {
+    #[into]
+    name: String,
+    #[try_from]
+    age: u8,
+    #[exact]
+    nickname: Option<String>,
+    #[exact]
+    newsletter: bool,
+}
+
+#[test]
+fn mixed_try_from_parameters() {
+    let signup = Signup::new("Jorge", 23_u64, None, true).unwrap();
+    assert_eq!(signup, Signup { name: "Jorge".to_string(), age: 23, nickname: None, newsletter: true });
+    assert_eq!(Signup::try_from(("Jorge", 23_i32, None, true)), Ok(signup));
+
+    // The error enum only has the variant of 'age'.
+    match Signup::new("Jorge", 300_u16, None, false).unwrap_err() {
+        SignupNewError::AgeError(error) => assert_eq!(error, u8::try_from(300_u16).unwrap_err()),
+    }
+    match Signup::try_from(("Jorge", -1_i32, None, false)).unwrap_err() {
+        SignupTryFromError::AgeError(error) => assert_eq!(error, u8::try_from(-1_i32).unwrap_err()),
+    }
+}
+
+// Fields given a converter take the converter's input type, where converters returning a Result
+// declare their error, which the TryFrom pattern returns as the field's variant.
+fn parse_port(port: &str) -> Result<u16, core::num::ParseIntError> {
+    port.parse()
+}
+
+#[constructor(named(new))]
+#[constructor(named(parse), pattern(TryFrom), convert(port(with = parse_port, from = &str, error = core::num::ParseIntError)),
+error_enum_metadata(#[derive(Debug, PartialEq)]))]
+#[constructor(named(builder), pattern(Builder), convert(port(with = u16::from, from = u8)))]
+#[derive(Debug, PartialEq, From)]
+pub struct Endpoint {
+    host: String,
+    port: u16,
+    #[convert(with = core::time::Duration::from_millis, from = u64)]
+    timeout: core::time::Duration,
+}
+
+#[test]
+fn converted_fields() {
+    let endpoint = Endpoint { host: "localhost".to_string(), port: 8080, timeout: core::time::Duration::from_secs(2) };
+    assert_eq!(Endpoint::new("localhost".to_string(), 8080, 2000), endpoint);
+    assert_eq!(Endpoint::from(("localhost".to_string(), 8080, 2000)), endpoint);
+    assert_eq!(Endpoint::parse("localhost", "8080", 2000), Ok(endpoint));
+    assert_eq!(Endpoint::parse("localhost", "http", 2000), Err(EndpointParseError::PortError("http".parse::<u16>().unwrap_err())));
+
+    let endpoint = Endpoint::builder().host("localhost".to_string()).port(80).timeout(500).build();
+    assert_eq!(endpoint.ok(), Some(Endpoint { host: "localhost".to_string(), port: 80, timeout: core::time::Duration::from_millis(500) }));
+}
+
+// Converters that may fail declared on a field only apply to TryFrom constructors, so other
+// patterns can only give the field its default.
+fn parse_retries(retries: &str) -> Result<u8, core::num::ParseIntError> {
+    retries.parse()
+}
+
+#[constructor(named(new), fields(), defaults(retries(3)))]
+#[constructor(named(parse), pattern(TryFrom), error_enum_metadata(#[derive(Debug, PartialEq)]))]
+#[derive(Debug, PartialEq)]
+pub struct RetryPolicy {
+    #[convert(with = parse_retries, from = &str, error = core::num::ParseIntError)]
+    retries: u8,
+}
+
+#[test]
+fn fallible_field_converters() {
+    assert_eq!(RetryPolicy::new(), RetryPolicy { retries: 3 });
+    assert_eq!(RetryPolicy::parse("3"), Ok(RetryPolicy { retries: 3 }));
+    assert_eq!(RetryPolicy::parse("three"), Err(RetryPolicyParseError::RetriesError("three".parse::<u8>().unwrap_err())));
+}
+
+// The FromStr pattern parses each parameter from a '&str', and also creates a function taking
+// them as a row, which fails with 'RowLength' when it doesn't hold a value for each field.
+#[constructor(named(parse_new), pattern(FromStr), error_enum_metadata(#[derive(Debug, PartialEq)]))]
+#[constructor(named(auto), pattern(FromStr), fields(name), from_strs_named(from_name_row), defaults(age(0)))]
+#[derive(Debug, PartialEq)]
+pub struct ParsedCharacter<Tag: core::str::FromStr> {
+    name: String,
+    #[validate(range(0..150))]
+    age: u8,
+    tag: Tag,
+}
+
+#[test]
+fn from_str_pattern() {
+    let character = ParsedCharacter { name: "Jorge".to_string(), age: 23, tag: 'J' };
+    assert_eq!(ParsedCharacter::parse_new("Jorge", "23", "J"), Ok(character));
+    let character = ParsedCharacter { name: "Jorge".to_string(), age: 23, tag: 'J' };
+    assert_eq!(ParsedCharacter::from_strs(&["Jorge", "23", "J"]), Ok(character));
+    assert_eq!(ParsedCharacter::<char>::from_strs(&["Jorge", "23"]), Err(ParsedCharacterParseNewError::RowLength { expected: 3, found: 2 }));
+    assert_eq!(ParsedCharacter::<char>::parse_new("Jorge", "old", "J"), Err(ParsedCharacterParseNewError::AgeError("old".parse::<u8>().unwrap_err())));
+    assert_eq!(ParsedCharacter::<char>::parse_new("Jorge", "200", "J"), Err(ParsedCharacterParseNewError::AgeOutOfRange));
+    assert_eq!(ParsedCharacter::<u8>::parse_new("Jorge", "23", "x"), Err(ParsedCharacterParseNewError::TagError("x".parse::<u8>().unwrap_err())));
+
+    let character = ParsedCharacter::<u8>::parse_with_name("Jorge").ok().unwrap();
+    assert_eq!(character, ParsedCharacter { name: "Jorge".to_string(), age: 0, tag: 0 });
+    assert!(ParsedCharacter::<u8>::from_name_row(&["Jorge"]).is_ok());
+}
+
+// With 'error_enum_impls', the error enums implement Display and Error, naming the struct and
+// field that failed, and giving the conversion's error as their source.
+#[constructor(named(new), pattern(TryFrom), error_enum_impls, error_enum_metadata(#[derive(Debug)]))]
+#[constructor(named(parse), pattern(FromStr), error_enum_impls, error_enum_metadata(#[derive(Debug)]))]
+#[derive(Debug, TryFrom)]
+#[enum_error_meta(#[derive(Debug)])]
+#[error_enum_impls]
+pub struct ReportedCharacter {
+    name: String,
+    #[validate(range(0..150))]
+    age: u8,
+}
+
+fn new_reported_character(age: u16) -> Result<ReportedCharacter, Box<dyn std::error::Error>> {
+    Ok(ReportedCharacter::new("Jorge", age)?)
+}
+
+#[test]
+fn error_enums_implement_error() {
+    use std::error::Error;
+
+    let overflow = u8::try_from(300_u16).unwrap_err();
+    let error = new_reported_character(300).unwrap_err();
+    assert_eq!(error.to_string(), format!("ReportedCharacter::age: {overflow}"));
+    assert_eq!(error.source().map(ToString::to_string), Some(overflow.to_string()));
+    assert_eq!(new_reported_character(200).unwrap_err().to_string(), "ReportedCharacter::age isn't in the range 0..150");
+    assert!(new_reported_character(23).is_ok());
+
+    let error = ReportedCharacter::try_from(("Jorge", 300_u16)).unwrap_err();
+    assert_eq!(error.to_string(), format!("ReportedCharacter::age: {overflow}"));
+
+    let error = ReportedCharacter::from_strs(&["Jorge"]).unwrap_err();
+    assert_eq!(error.to_string(), "ReportedCharacter: expected a row of 2 values, but found 1");
+    assert!(error.source().is_none());
+    let error = ReportedCharacter::parse("Jorge", "old").unwrap_err();
+    assert_eq!(error.source().map(ToString::to_string), Some("old".parse::<u8>().unwrap_err().to_string()));
+}
+
+// Errors held by the variants only need to implement Display, like 'String', being given as the
+// source only when they implement Error.
+fn parse_nickname(nickname: &str) -> Result<String, String> {
+    if nickname.is_empty() { Err("empty nickname".to_string()) } else { Ok(nickname.to_string()) }
+}
+
+#[constructor(named(new), pattern(TryFrom), error_enum_impls, error_enum_metadata(#[derive(Debug)]))]
+#[derive(Debug)]
+pub struct NicknamedCharacter {
+    #[convert(with = parse_nickname, from = &str, error = String)]
+    nickname: String,
+    #[try_from(u16)]
+    age: u8,
+}
+
+#[test]
+fn error_enums_hold_non_error_types() {
+    use std::error::Error;
+
+    let error = NicknamedCharacter::new("", 23).unwrap_err();
+    assert_eq!(error.to_string(), "NicknamedCharacter::nickname: empty nickname");
+    assert!(error.source().is_none());
+    let error = NicknamedCharacter::new("Jorge", 300).unwrap_err();
+    assert!(error.source().is_some());
+}
+
+// Declaring the types fields are received as, through 'fields(age: u16)' or '#[try_from(u16)]',
+// makes their variants concrete, so the error enum can be named without generic parameters.
+#[constructor(named(new), pattern(TryFrom), fields(name: &str, age: u16), error_enum_metadata(#[derive(Debug, PartialEq)]))]
+#[derive(Debug, PartialEq, TryFrom)]
+#[enum_error_meta(#[derive(Debug, PartialEq)])]
+pub struct ConcreteCharacter {
+    #[try_from(&str)]
+    name: String,
+    #[try_from(i64)]
+    age: u8,
+}
+
+fn new_concrete_character(age: u16) -> Result<ConcreteCharacter, ConcreteCharacterNewError> {
+    ConcreteCharacter::new("Jorge", age)
+}
+
+#[test]
+fn concrete_error_enums() {
+    assert_eq!(new_concrete_character(23), Ok(ConcreteCharacter { name: "Jorge".to_string(), age: 23 }));
+    assert_eq!(new_concrete_character(300), Err(ConcreteCharacterNewError::AgeError(u8::try_from(300_u16).unwrap_err())));
+
+    let error: ConcreteCharacterTryFromError = ConcreteCharacter::try_from(("Jorge", -1_i64)).unwrap_err();
+    assert_eq!(error, ConcreteCharacterTryFromError::AgeError(u8::try_from(-1_i64).unwrap_err()));
+}
+
+// Types hiding a lifetime are declared writing it, as in 'Chars<'_>', for their variants to name them.
+#[derive(Debug, PartialEq)]
+pub struct Initials(String);
+
+impl<'a> TryFrom<std::str::Chars<'a>> for Initials {
+    type Error = usize;
+
+    fn try_from(chars: std::str::Chars<'a>) -> Result<Self, Self::Error> {
+        let initials = chars.collect::<String>();
+        if initials.len() > 3 { Err(initials.len()) } else { Ok(Initials(initials)) }
+    }
+}
+
+#[constructor(named(new), pattern(TryFrom), error_enum_metadata(#[derive(Debug, PartialEq)]))]
+#[derive(Debug, PartialEq)]
+pub struct SignedCharacter {
+    #[try_from(std::str::Chars<'_>)]
+    initials: Initials,
+}
+
+#[test]
+fn declared_types_with_lifetimes() {
+    assert_eq!(SignedCharacter::new("JRV".chars()), Ok(SignedCharacter { initials: Initials("JRV".to_string()) }));
+    let error: SignedCharacterNewError = SignedCharacter::new("Jorge".chars()).unwrap_err();
+    assert_eq!(error, SignedCharacterNewError::InitialsError(5));
+}
+
+// Collecting errors through 'errors(collect)' converts and checks every field even after one fails,
+// returning a 'CollectedCharacterNewErrors' holding the first error found on each of them.
+#[derive(Debug, PartialEq)]
+pub struct TooShortForAge(u8);
+
+impl std::fmt::Display for TooShortForAge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "too short for being {} years old", self.0)
+    }
+}
+
+impl std::error::Error for TooShortForAge {}
+
+fn check_collected_character(character: &CollectedCharacter) -> Result<(), TooShortForAge> {
+    match character.age < 100 || character.height > 100 {
+        true => Ok(()),
+        false => Err(TooShortForAge(character.age)),
+    }
+}
+
+#[constructor(
+named(new),
+pattern(TryFrom),
+errors(collect),
+error_enum_impls,
+validate(check_collected_character, TooShortForAge),
+error_enum_metadata(#[derive(Debug, PartialEq)])
+)]
+#[derive(Debug, PartialEq, TryFrom)]
+#[enum_error_meta(#[derive(Debug, PartialEq)])]
+#[errors(collect)]
+pub struct CollectedCharacter {
+    #[exact]
+    #[validate(non_empty)]
+    name: String,
+    #[try_from(u16)]
+    #[validate(range(0..150))]
+    age: u8,
+    #[try_from(i64)]
+    height: u16,
+}
+
+#[test]
+fn collected_errors() {
+    use std::error::Error;
+
+    assert_eq!(CollectedCharacter::new(String::from("Jorge"), 23_u16, 170_i64), Ok(CollectedCharacter { name: "Jorge".to_string(), age: 23, height: 170 }));
+
+    let errors = CollectedCharacter::new(String::from(""), 300_u16, -1_i64).unwrap_err();
+    assert_eq!(errors.name, Some(CollectedCharacterNewError::NameEmpty));
+    assert_eq!(errors.age, Some(CollectedCharacterNewError::AgeError(u8::try_from(300_u16).unwrap_err())));
+    assert_eq!(errors.height, Some(CollectedCharacterNewError::HeightError(u16::try_from(-1_i64).unwrap_err())));
+    assert_eq!(errors.validation, None);
+    assert_eq!(errors.iter().map(|(field_name, _)| field_name).collect::<Vec<_>>(), ["name", "age", "height"]);
+    assert_eq!(errors.to_string().split("; ").count(), 3);
+    assert!(errors.source().is_none());
+
+    let errors = CollectedCharacter::new(String::from("Jorge"), 200_u16, 170_i64).unwrap_err();
+    assert_eq!(errors.iter().map(|(field_name, _)| field_name).collect::<Vec<_>>(), ["age"]);
+    assert_eq!(errors.to_string(), "CollectedCharacter::age isn't in the range 0..150");
+
+    let errors = CollectedCharacter::new(String::from("Jorge"), 120_u16, 90_i64).unwrap_err();
+    assert_eq!(errors.validation, Some(CollectedCharacterNewError::ValidationError(TooShortForAge(120))));
+
+    let errors: CollectedCharacterTryFromErrors = CollectedCharacter::try_from((String::new(), 23_u16, -1_i64)).unwrap_err();
+    assert_eq!(errors.iter().map(|(field_name, _)| field_name).collect::<Vec<_>>(), ["name", "height"]);
+}
+
+// Structs collecting errors only take the standard derives of the error enum's metadata, so
+// metadata like '#[repr(u8)]' stays on the enum, while 'iter()' is only there when it derives Debug.
+#[constructor(named(new), pattern(TryFrom), errors(collect), error_enum_metadata(#[repr(u8)]))]
+#[derive(Debug, PartialEq)]
+pub struct RankedCharacter {
+    #[try_from(u16)]
+    age: u8,
+    #[try_from(i64)]
+    rank: u8,
+}
+
+#[test]
+fn collected_errors_take_standard_derives() {
+    assert_eq!(RankedCharacter::new(23, 1).ok(), Some(RankedCharacter { age: 23, rank: 1 }));
+    let errors: RankedCharacterNewErrors = RankedCharacter::new(300, -1).err().unwrap();
+    assert!(matches!(errors.age, Some(RankedCharacterNewError::AgeError(_))));
+    assert!(matches!(errors.rank, Some(RankedCharacterNewError::RankError(_))));
+}
+
+// Fields can be named 'errors' even when errors are collected, as the generated code stores them
+// on a local named so it doesn't shadow them.
+#[constructor(named(new), pattern(TryFrom), errors(collect))]
+#[derive(Debug, PartialEq)]
+pub struct ErrorsCounter {
+    #[try_from(i64)]
+    errors: u8,
+    #[exact]
+    #[validate(range(0..10))]
+    warnings: u8,
+}
+
+#[test]
+fn collected_errors_of_a_field_named_errors() {
+    assert_eq!(ErrorsCounter::new(3, 1).ok(), Some(ErrorsCounter { errors: 3, warnings: 1 }));
+    let collected: ErrorsCounterNewErrors = ErrorsCounter::new(-1, 20).err().unwrap();
+    assert!(matches!(collected.errors, Some(ErrorsCounterNewError::ErrorsError(_))));
+    assert!(matches!(collected.warnings, Some(ErrorsCounterNewError::WarningsOutOfRange)));
+}
+
+// Variants holding the errors of fields can be named through '#[error_variant(...)]', or after a
+// template like 'error_variants_named("Invalid{Field}")', while 'field_name()' and 'field_index()'
+// tell which field any variant is about.
+#[constructor(
+named(new),
+pattern(TryFrom),
+error_variants_named("Invalid{Field}"),
+validate(check_labeled_character, ImpossibleAge),
+error_enum_metadata(#[derive(Debug, PartialEq)])
+)]
+#[constructor(named(parse), pattern(FromStr), error_enum_metadata(#[derive(Debug, PartialEq)]))]
+#[derive(Debug, PartialEq, TryFrom)]
+#[enum_error_meta(#[derive(Debug, PartialEq)])]
+#[error_variants_named("Wrong{Field}")]
+pub struct LabeledCharacter {
+    #[error_variant(UnreadableId)]
+    #[try_from(i64)]
+    id: u32,
+    #[validate(non_empty)]
+    name: String,
+    #[try_from(u16)]
+    age: u8,
+}
+
+fn check_labeled_character(character: &LabeledCharacter) -> Result<(), ImpossibleAge> {
+    match character.age < 150 {
+        true => Ok(()),
+        false => Err(ImpossibleAge(character.age)),
+    }
+}
+
+#[test]
+fn error_variant_names() {
+    let error = LabeledCharacter::new(1_i64, "Jorge".to_string(), 300_u16).unwrap_err();
+    assert_eq!(error, LabeledCharacterNewError::InvalidAge(u8::try_from(300_u16).unwrap_err()));
+    assert_eq!((error.field_name(), error.field_index()), ("age", 2));
+
+    let error = LabeledCharacter::new(-1_i64, "Jorge".to_string(), 23_u16).unwrap_err();
+    assert_eq!(error, LabeledCharacterNewError::UnreadableId(u32::try_from(-1_i64).unwrap_err()));
+    assert_eq!((error.field_name(), error.field_index()), ("id", 0));
+
+    let error = LabeledCharacter::new(1_i64, String::new(), 23_u16).unwrap_err();
+    assert_eq!((error.field_name(), error.field_index()), ("name", 1));
+
+    let error = LabeledCharacter::new(1_i64, "Jorge".to_string(), 200_u16).unwrap_err();
+    assert_eq!((error.field_name(), error.field_index()), ("validation", 3));
+
+    let error = LabeledCharacter::try_from((1_i64, "Jorge".to_string(), 300_u16)).unwrap_err();
+    assert!(matches!(error, LabeledCharacterTryFromError::WrongAge(_)));
+    assert_eq!(error.field_name(), "age");
+
+    let error = LabeledCharacter::parse("1", "Jorge", "old").unwrap_err();
+    assert!(matches!(error, LabeledCharacterParseError::AgeError(_)));
+    assert_eq!((error.field_name(), error.field_index()), ("age", 2));
+    let error = LabeledCharacter::from_strs(&["1"]).unwrap_err();
+    assert_eq!((error.field_name(), error.field_index()), ("row", 3));
+}
+
+// 'RowLength' is only taken by the FromStr pattern, so TryFrom variants can be named like it, while
+// the From derive also declares '#[error_variant]' for structs also having TryFrom constructors.
+#[constructor(named(new), pattern(TryFrom), error_enum_metadata(#[derive(Debug, PartialEq)]))]
+#[derive(Debug, PartialEq, From)]
+pub struct Row {
+    #[error_variant(RowLength)]
+    length: u8,
+}
+
+#[test]
+fn error_variants_named_like_other_patterns_variants() {
+    assert_eq!(Row::from(3_u8), Row { length: 3 });
+    assert_eq!(Row::new(300_u16), Err(RowNewError::RowLength(u8::try_from(300_u16).unwrap_err())));
+}
+
+// Returning the inputs through 'errors(return_inputs)' gives back the values received when the
+// constructor fails, where the values before the one that failed are held already converted, and
+// every value is held converted when the validator fails.
+fn check_buffered_character(character: &BufferedCharacter) -> Result<(), TooShortForAge> {
+    match character.age < 150 {
+        true => Ok(()),
+        false => Err(TooShortForAge(character.age)),
+    }
+}
+
+#[constructor(
+named(new),
+pattern(TryFrom),
+errors(return_inputs),
+error_enum_impls,
+validate(check_buffered_character, TooShortForAge),
+error_enum_metadata(#[derive(Debug, PartialEq)])
+)]
+#[derive(Debug, PartialEq, TryFrom)]
+#[enum_error_meta(#[derive(Debug, PartialEq)])]
+#[errors(return_inputs)]
+pub struct BufferedCharacter {
+    #[validate(non_empty)]
+    buffer: Vec<u8>,
+    #[try_from(i64)]
+    id: u32,
+    #[try_from(u16)]
+    age: u8,
+}
+
+#[test]
+fn returned_inputs() {
+    assert_eq!(BufferedCharacter::new(vec![1], 1, 23), Ok(BufferedCharacter { buffer: vec![1], id: 1, age: 23 }));
+
+    let error = BufferedCharacter::new(vec![1], -1, 23).unwrap_err();
+    assert_eq!(error.error, BufferedCharacterNewError::IdError(u32::try_from(-1_i64).unwrap_err()));
+    assert_eq!(error.to_string(), format!("BufferedCharacter::id: {}", u32::try_from(-1_i64).unwrap_err()));
+    assert_eq!((error.buffer, error.buffer_input), (Some(vec![1]), None));
+    assert_eq!((error.id, error.id_input), (None, None));
+    assert_eq!((error.age, error.age_input), (None, Some(23)));
+
+    let error = BufferedCharacter::new(vec![1], 1, 300).unwrap_err();
+    assert_eq!((error.buffer, error.id, error.age, error.age_input), (Some(vec![1]), Some(1), None, None));
+
+    let error = BufferedCharacter::new(Vec::new(), 1, 23).unwrap_err();
+    assert_eq!(error.error, BufferedCharacterNewError::BufferEmpty);
+    assert_eq!((error.buffer, error.id, error.age), (Some(Vec::new()), Some(1), Some(23)));
+
+    let error = BufferedCharacter::new(vec![1], 1, 200).unwrap_err();
+    assert_eq!(error.error, BufferedCharacterNewError::ValidationError(TooShortForAge(200)));
+    assert_eq!((error.buffer, error.id, error.age), (Some(vec![1]), Some(1), Some(200)));
+
+    let error: BufferedCharacterTryFromErrorWithInputs<_, Vec<u8>, Vec<u8>, u32, i64, u8, u16> = BufferedCharacter::try_from((vec![2], -1_i64, 23_u16)).unwrap_err();
+    assert_eq!((error.buffer, error.age_input), (Some(vec![2]), Some(23)));
+}
+
+// Constructors sharing their error enum through 'error_enum(define = ...)' and
+// 'error_enum(use = ...)' return the same type, which has a variant for every field declaring the
+// type it's received as, even those only some constructors receive, so every constructor receives
+// them as the field declares.
+#[constructor(
+named(new),
+pattern(TryFrom),
+fields(name, age),
+error_enum(define = SharedCharacterError),
+error_enum_impls,
+error_enum_metadata(#[derive(Debug, PartialEq)])
+)]
+#[constructor(named(with_age), pattern(TryFrom), fields(age), defaults(name("Anonymous".to_string())), error_enum(use = SharedCharacterError))]
+#[constructor(named(with_level), pattern(TryFrom), fields(name, level), error_enum(use = SharedCharacterError))]
+#[derive(Debug, PartialEq, TryFrom)]
+#[error_enum(use = SharedCharacterError)]
+pub struct SharedCharacter {
+    #[validate(non_empty)]
+    #[exact]
+    name: String,
+    #[error_variant(InvalidAge)]
+    #[try_from(u64)]
+    age: u8,
+    #[no_from]
+    #[try_from(i32)]
+    level: u8,
+}
+
+fn shared_character_errors(age: u64) -> Vec<SharedCharacterError> {
+    [
+        SharedCharacter::new(String::new(), 23).err(),
+        SharedCharacter::new("Jorge".to_string(), age).err(),
+        SharedCharacter::with_age(age).err(),
+        SharedCharacter::try_from(("Jorge".to_string(), age)).err(),
+    ].into_iter().flatten().collect()
+}
+
+#[test]
+fn shared_error_enums() {
+    use std::error::Error;
+
+    assert_eq!(SharedCharacter::with_age(23), Ok(SharedCharacter { name: "Anonymous".to_string(), age: 23, level: 0 }));
+    assert_eq!(shared_character_errors(23), [SharedCharacterError::NameEmpty]);
+
+    let overflow = u8::try_from(300_u16).unwrap_err();
+    let errors = shared_character_errors(300);
+    assert_eq!(errors, [
+        SharedCharacterError::NameEmpty,
+        SharedCharacterError::InvalidAge(overflow.clone()),
+        SharedCharacterError::InvalidAge(overflow.clone()),
+        SharedCharacterError::InvalidAge(overflow.clone()),
+    ]);
+    assert_eq!(errors[1].to_string(), format!("SharedCharacter::age: {overflow}"));
+    assert_eq!((errors[1].field_name(), errors[1].field_index()), ("age", 1));
+    assert!(errors[1].source().is_some());
+
+    assert_eq!(SharedCharacter::with_level("Jorge".to_string(), 3), Ok(SharedCharacter { name: "Jorge".to_string(), age: 0, level: 3 }));
+    let error: SharedCharacterError = SharedCharacter::with_level("Jorge".to_string(), -1).unwrap_err();
+    assert_eq!(error, SharedCharacterError::LevelError(u8::try_from(-1_i32).unwrap_err()));
+    assert_eq!((error.field_name(), error.field_index()), ("level", 2));
+}
+
+// Asking for 'parts' splits the struct back into the values its constructor receives, through
+// 'into_parts()', 'as_parts()' and 'From<YourStruct>' for their tuple, leaving out the rest.
+#[derive(Debug, PartialEq, From)]
+#[parts]
+pub struct SplitCharacter {
+    name: &'static str,
+    age: u8,
+    #[no_from]
+    times_appeared: u8,
+}
+
+#[constructor(named(new), fields(name), defaults(age(23)), parts)]
+#[derive(Debug, PartialEq)]
+pub struct NamedOnlyCharacter<Name> {
+    name: Name,
+    age: u8,
+}
+
+// A single value whose type is a reference to a generic parameter gets no 'From' for its tuple, as
+// Rust doesn't allow it.
+#[derive(Debug, PartialEq, From)]
+#[parts]
+pub struct BorrowedCharacter<'a, Name> {
+    name: &'a Name,
+}
+
+#[test]
+fn parts() {
+    let character = SplitCharacter::from(("Jorge", 23));
+    assert_eq!(character.as_parts(), (&"Jorge", &23));
+    assert_eq!(<(&'static str, u8)>::from(character), ("Jorge", 23));
+    assert_eq!(SplitCharacter { name: "Jorge", age: 23, times_appeared: 2 }.into_parts(), ("Jorge", 23));
+
+    let character = NamedOnlyCharacter::new("Jorge".to_string());
+    assert_eq!(character.as_parts(), &"Jorge".to_string());
+    assert_eq!(character.into_parts(), "Jorge".to_string());
+
+    let name = "Jorge".to_string();
+    assert_eq!(BorrowedCharacter::from(&name).into_parts(), &name);
+}
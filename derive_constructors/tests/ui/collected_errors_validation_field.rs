@@ -0,0 +1,14 @@
+use derive_constructors::constructor;
+
+// Collected errors hold the validator's error on 'validation', so no collected field can take it.
+fn check_character(_: &Character) -> Result<(), ()> {
+    Ok(())
+}
+
+#[constructor(named(new), pattern(TryFrom), errors(collect), validate(check_character, ()))]
+pub struct Character {
+    #[error_variant(InvalidValidation)]
+    validation: u8,
+}
+
+fn main() {}
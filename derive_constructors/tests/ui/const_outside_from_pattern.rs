@@ -0,0 +1,9 @@
+use derive_constructors::constructor;
+
+// Only named constructors of the From pattern can be const.
+#[constructor(named(new), pattern(TryFrom), const)]
+pub struct Character {
+    age: u8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use derive_constructors::constructor;
+
+// Const constructors can't call 'Default::default()' to initialize 'level'.
+#[constructor(named(new), fields(age), const)]
+pub struct Character {
+    age: u8,
+    level: u8,
+}
+
+fn main() {}
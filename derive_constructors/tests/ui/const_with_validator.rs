@@ -0,0 +1,13 @@
+use derive_constructors::constructor;
+
+// Const constructors can't call a validator.
+fn check_character(_: &Character) -> Result<(), ()> {
+    Ok(())
+}
+
+#[constructor(named(new), const, validate(check_character, ()))]
+pub struct Character {
+    age: u8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use derive_constructors::constructors;
+
+// Entries of '#[constructors]' are already named after themselves.
+#[constructors(new(named(create)))]
+pub struct Character {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use derive_constructors::constructors;
+
+// Entries of '#[constructors]' only take the options of '#[constructor]'.
+#[constructors(new(fields(name), nmed(other)))]
+pub struct Character {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use derive_constructors::constructor;
+
+// 'convert' can only be given for fields the constructor receives.
+#[constructor(named(new), fields(name), convert(timeout(with = core::time::Duration::from_millis, from = u64)))]
+pub struct Character {
+    name: String,
+    timeout: core::time::Duration,
+}
+
+fn main() {}
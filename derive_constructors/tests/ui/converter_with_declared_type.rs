@@ -0,0 +1,10 @@
+use derive_constructors::constructor;
+
+// Fields given a converter are received as the converter's input type.
+#[constructor(named(new), pattern(TryFrom), fields(timeout: u32))]
+pub struct Character {
+    #[convert(with = core::time::Duration::from_millis, from = u64)]
+    timeout: core::time::Duration,
+}
+
+fn main() {}
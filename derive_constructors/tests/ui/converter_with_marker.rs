@@ -0,0 +1,11 @@
+use derive_constructors::constructor;
+
+// Fields given a converter are converted by it, rather than through '#[into]'.
+#[constructor(named(new), pattern(TryFrom))]
+pub struct Character {
+    #[into]
+    #[convert(with = core::time::Duration::from_millis, from = u64)]
+    timeout: core::time::Duration,
+}
+
+fn main() {}
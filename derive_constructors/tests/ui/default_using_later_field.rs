@@ -0,0 +1,13 @@
+use derive_constructors::From;
+
+// Defaults are initialized in the order fields are declared, so 'initials' can't use 'nickname'.
+#[derive(From)]
+pub struct Character {
+    name: String,
+    #[no_from(nickname.chars().take(2).collect())]
+    initials: String,
+    #[no_from(name.to_lowercase())]
+    nickname: String,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use derive_constructors::constructor;
+
+// 'name' and 'age' can't both get a variant named 'Invalid'.
+#[constructor(named(new), pattern(TryFrom))]
+pub struct Character {
+    #[error_variant(Invalid)]
+    name: String,
+    #[error_variant(Invalid)]
+    age: u8,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use derive_constructors::constructor;
+
+// 'NameEmpty' is already the variant of the check of 'name'.
+#[constructor(named(new), pattern(TryFrom))]
+pub struct Character {
+    #[validate(non_empty)]
+    name: String,
+    #[error_variant(NameEmpty)]
+    age: u8,
+}
+
+fn main() {}
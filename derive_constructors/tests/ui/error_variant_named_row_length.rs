@@ -0,0 +1,10 @@
+use derive_constructors::constructor;
+
+// The FromStr pattern already has a 'RowLength' variant.
+#[constructor(named(parse), pattern(FromStr))]
+pub struct Character {
+    #[error_variant(RowLength)]
+    age: u8,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use derive_constructors::From;
+
+// Validators make constructing the struct fallible, which 'From' can't be.
+fn check_character(_: &Character) -> Result<(), ()> {
+    Ok(())
+}
+
+#[derive(From)]
+#[validate(check_character, ())]
+pub struct Character {
+    age: u8,
+}
+
+fn main() {}
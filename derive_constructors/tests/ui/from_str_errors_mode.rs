@@ -0,0 +1,9 @@
+use derive_constructors::constructor;
+
+// Only the TryFrom pattern can collect errors or return its inputs.
+#[constructor(named(parse), pattern(FromStr), errors(collect))]
+pub struct Character {
+    age: u8,
+}
+
+fn main() {}
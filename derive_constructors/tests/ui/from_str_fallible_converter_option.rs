@@ -0,0 +1,13 @@
+use derive_constructors::constructor;
+
+// The FromStr pattern parses its fields, so it can't be given converters that may fail.
+fn parse_age(age: &str) -> Result<u8, core::num::ParseIntError> {
+    age.parse()
+}
+
+#[constructor(named(parse), pattern(FromStr), convert(age(with = parse_age, from = &str, error = core::num::ParseIntError)))]
+pub struct Character {
+    age: u8,
+}
+
+fn main() {}
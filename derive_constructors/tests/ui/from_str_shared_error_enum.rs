@@ -0,0 +1,9 @@
+use derive_constructors::constructor;
+
+// Only the TryFrom pattern can share its error enum.
+#[constructor(named(parse), pattern(FromStr), error_enum(define = CharacterError))]
+pub struct Character {
+    age: u8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use derive_constructors::constructor;
+
+// The Builder pattern has no error to report the checks of 'name' through.
+#[constructor(named(builder), pattern(Builder))]
+pub struct Character {
+    #[validate(non_empty)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use derive_constructors::From;
+
+fn parse_age(age: &str) -> Result<u8, core::num::ParseIntError> {
+    age.parse()
+}
+
+// The From derive can't fail, so it can't convert 'age' through a converter that may fail.
+#[derive(From)]
+pub struct Character {
+    name: String,
+    #[convert(with = parse_age, from = &str, error = core::num::ParseIntError)]
+    age: u8,
+}
+
+fn main() {}
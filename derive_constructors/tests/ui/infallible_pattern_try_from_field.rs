@@ -0,0 +1,11 @@
+use derive_constructors::constructor;
+
+// The Into pattern can't fail, so it can't convert 'age' through TryFrom.
+#[constructor(named(new), pattern(Into))]
+pub struct Character {
+    name: String,
+    #[try_from(u16)]
+    age: u8,
+}
+
+fn main() {}
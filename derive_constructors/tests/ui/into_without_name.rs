@@ -0,0 +1,9 @@
+use derive_constructors::constructor;
+
+// The Into pattern creates a function, so it needs a name.
+#[constructor(pattern(Into))]
+pub struct Character {
+    name: String,
+}
+
+fn main() {}
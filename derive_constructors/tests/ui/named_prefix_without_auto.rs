@@ -0,0 +1,9 @@
+use derive_constructors::constructor;
+
+// 'named_prefix' only applies to constructors named through 'named(auto)'.
+#[constructor(named(new), named_prefix(from))]
+pub struct Character {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use derive_constructors::constructor;
+
+// Only one constructor can define 'into_parts' and 'as_parts'.
+#[constructor(named(new), parts)]
+#[constructor(named(with_name), fields(name), parts)]
+pub struct Character {
+    name: String,
+    age: u8,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use derive_constructors::constructor;
+
+// Returned inputs are held by a struct, which can't name the elided lifetime of '&str'.
+fn parse_age(age: &str) -> Result<u8, core::num::ParseIntError> {
+    age.parse()
+}
+
+#[constructor(named(new), pattern(TryFrom), errors(return_inputs))]
+pub struct Character {
+    #[convert(with = parse_age, from = &str, error = core::num::ParseIntError)]
+    age: u8,
+}
+
+fn main() {}
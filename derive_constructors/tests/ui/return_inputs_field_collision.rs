@@ -0,0 +1,11 @@
+use derive_constructors::constructor;
+
+// Returned inputs are held on fields like 'age_input', which 'age_input' would collide with.
+#[constructor(named(new), pattern(TryFrom), errors(return_inputs))]
+pub struct Character {
+    age: u8,
+    #[exact]
+    age_input: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use derive_constructors::constructor;
+
+// A field is converted through only one of '#[into]', '#[try_from]' or '#[exact]'.
+#[constructor(named(new), pattern(TryFrom))]
+pub struct Character {
+    #[into]
+    #[exact]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use derive_constructors::constructor;
+
+// Shared error enums can't be combined with 'errors(...)', which return their own types.
+#[constructor(named(new), pattern(TryFrom), error_enum(define = CharacterError), errors(collect))]
+pub struct Character {
+    #[try_from(u16)]
+    age: u8,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use derive_constructors::constructor;
+
+// Only the constructor defining a shared error enum gives its metadata.
+#[constructor(named(new), pattern(TryFrom), error_enum(define = CharacterError))]
+#[constructor(named(with_age), pattern(TryFrom), error_enum(use = CharacterError), error_enum_metadata(#[derive(Debug)]))]
+pub struct Character {
+    #[try_from(u16)]
+    age: u8,
+}
+
+fn main() {}
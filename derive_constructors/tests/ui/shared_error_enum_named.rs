@@ -0,0 +1,10 @@
+use derive_constructors::constructor;
+
+// Shared error enums are named through 'error_enum' itself.
+#[constructor(named(new), pattern(TryFrom), error_enum(define = CharacterError), error_enum_named(OtherError))]
+pub struct Character {
+    #[try_from(u16)]
+    age: u8,
+}
+
+fn main() {}
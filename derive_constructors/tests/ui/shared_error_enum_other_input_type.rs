@@ -0,0 +1,11 @@
+use derive_constructors::constructor;
+
+// The shared enum holds the error of converting a 'u64', so 'with_age' can't receive an 'i64'.
+#[constructor(named(new), pattern(TryFrom), error_enum(define = CharacterError))]
+#[constructor(named(with_age), pattern(TryFrom), fields(age: i64), error_enum(use = CharacterError))]
+pub struct Character {
+    #[try_from(u64)]
+    age: u8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use derive_constructors::constructor;
+
+// 'error_enum' either defines or uses an enum.
+#[constructor(named(new), pattern(TryFrom), error_enum(declare = CharacterError))]
+pub struct Character {
+    #[try_from(u16)]
+    age: u8,
+}
+
+fn main() {}
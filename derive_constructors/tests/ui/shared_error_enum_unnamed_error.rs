@@ -0,0 +1,9 @@
+use derive_constructors::constructor;
+
+// Shared error enums can't name the error of 'age', which doesn't declare the type it takes.
+#[constructor(named(new), pattern(TryFrom), error_enum(define = CharacterError))]
+pub struct Character {
+    age: u8,
+}
+
+fn main() {}
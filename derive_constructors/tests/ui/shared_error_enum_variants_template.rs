@@ -0,0 +1,10 @@
+use derive_constructors::constructor;
+
+// Constructors sharing an error enum can't name its variants on their own.
+#[constructor(named(new), pattern(TryFrom), error_enum(define = CharacterError), error_variants_named("Invalid{Field}"))]
+pub struct Character {
+    #[try_from(u16)]
+    age: u8,
+}
+
+fn main() {}
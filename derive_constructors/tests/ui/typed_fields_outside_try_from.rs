@@ -0,0 +1,10 @@
+use derive_constructors::constructor;
+
+// Only the TryFrom pattern converts fields out of the types declared in 'fields'.
+#[constructor(named(new), fields(name, age: u16))]
+pub struct Character {
+    name: String,
+    age: u8,
+}
+
+fn main() {}
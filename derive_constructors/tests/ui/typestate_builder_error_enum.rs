@@ -0,0 +1,9 @@
+use derive_constructors::constructor;
+
+// Typestate builders catch missing fields at compile time, so they have no error enum.
+#[constructor(pattern(TypestateBuilder), error_enum_named(CharacterError))]
+pub struct Character {
+    name: String,
+}
+
+fn main() {}
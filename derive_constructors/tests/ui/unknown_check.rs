@@ -0,0 +1,10 @@
+use derive_constructors::constructor;
+
+// There's no 'positive' check.
+#[constructor(named(new), pattern(TryFrom))]
+pub struct Character {
+    #[validate(positive)]
+    age: i8,
+}
+
+fn main() {}
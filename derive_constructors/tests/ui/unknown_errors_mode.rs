@@ -0,0 +1,9 @@
+use derive_constructors::constructor;
+
+// Errors can only be collected or returned along with the inputs.
+#[constructor(named(new), pattern(TryFrom), errors(ignore))]
+pub struct Character {
+    age: u8,
+}
+
+fn main() {}
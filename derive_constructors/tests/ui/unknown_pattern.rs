@@ -0,0 +1,9 @@
+use derive_constructors::constructor;
+
+// There's no 'TryInto' pattern.
+#[constructor(named(new), pattern(TryInto))]
+pub struct Character {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use derive_constructors::constructor;
+
+// The error enum doesn't take the struct's generics, so it can't hold 'Rejected<T>'.
+pub struct Rejected<T>(T);
+
+fn check_character<T: Clone>(character: &Character<T>) -> Result<(), Rejected<T>> {
+    Err(Rejected(character.tag.clone()))
+}
+
+#[constructor(named(new), pattern(TryFrom), validate(check_character, Rejected<T>))]
+pub struct Character<T: Clone> {
+    tag: T,
+}
+
+fn main() {}
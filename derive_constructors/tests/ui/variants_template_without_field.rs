@@ -0,0 +1,10 @@
+use derive_constructors::constructor;
+
+// Every variant would be named 'Invalid'.
+#[constructor(named(new), pattern(TryFrom), error_variants_named("Invalid"))]
+pub struct Character {
+    name: String,
+    age: u8,
+}
+
+fn main() {}
@@ -0,0 +1,119 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use crate::parsing_structs::{ConstructorInfo, FieldConverter, FieldsInfo, FieldValidation, Validator};
+use crate::utils::{is_phantom_data, pretty_tokens};
+
+/// Doc attributes writing the given lines, like ```#[doc = " line"]```.
+pub(crate) fn doc_attributes<TLine: AsRef<str>>(lines: &[TLine]) -> TokenStream {
+    let lines = lines.iter()
+        .flat_map(|line| line.as_ref().split('\n').map(|line| format!(" {line}")).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    quote!(#(#[doc = #lines])*)
+}
+
+/// Docs for a constructor function, this is, its summary, which is replaced by the one given
+/// through ```doc("...")```, followed by its parameters along with their fields' docs, how the
+/// rest of fields are initialized and when it fails, if it does.
+pub(crate) fn constructor_docs(default_summary: String, fields_info: &FieldsInfo, constructor_info: &ConstructorInfo, errors: Option<String>) -> TokenStream {
+    let mut lines = vec![constructor_info.doc.clone().unwrap_or(default_summary)];
+    lines.extend(parameters_lines(fields_info, "Parameters"));
+    lines.extend(defaults_lines(fields_info));
+    if let Some(errors) = errors {
+        lines.extend(["".to_string(), "# Errors".to_string(), "".to_string(), errors]);
+    }
+    doc_attributes(&lines)
+}
+
+/// Docs for the function creating a builder, listing the values set through it and the defaults of
+/// the rest of fields.
+pub(crate) fn builder_constructor_docs(struct_name: &Ident, builder_name: &Ident, fields_info: &FieldsInfo, constructor_info: &ConstructorInfo) -> TokenStream {
+    let mut lines = vec![constructor_info.doc.clone()
+        .unwrap_or_else(|| format!("Creates a [`{builder_name}`] to build a [`{struct_name}`] by setting its values one by one."))];
+    lines.extend(parameters_lines(fields_info, "Values to set"));
+    lines.extend(defaults_lines(fields_info));
+    doc_attributes(&lines)
+}
+
+/// List of the parameters a constructor receives under the given heading, along with their fields'
+/// docs.
+fn parameters_lines(fields_info: &FieldsInfo, heading: &str) -> Vec<String> {
+    if fields_info.fields_names.is_empty() {
+        return Vec::new();
+    }
+    let parameters = fields_info.fields_names.iter().zip(fields_info.fields_types.iter()).zip(fields_info.fields_converters.iter()).zip(fields_info.fields_docs.iter())
+        .map(|(((field_name, field_type), converter), field_doc)| match converter {
+            Some(converter) => field_line(field_name, &format!("{}` through `{}", pretty_tokens(&converter.from), pretty_tokens(&converter.function)), field_doc),
+            None => field_line(field_name, &pretty_tokens(field_type), field_doc),
+        });
+    ["".to_string(), format!("# {heading}"), "".to_string()].into_iter()
+        .chain(parameters)
+        .collect()
+}
+
+/// List of the fields that aren't received, along with the expressions initializing them.
+fn defaults_lines(fields_info: &FieldsInfo) -> Vec<String> {
+    let defaults = fields_info.no_from_fields_names.iter().zip(fields_info.no_from_fields_types.iter())
+        .zip(fields_info.no_from_fields_initializers.iter())
+        .filter(|((_, field_type), _)| !is_phantom_data(field_type))
+        .map(|((field_name, _), initializer)| format!("- `{field_name}` is initialized as `{}`.", pretty_tokens(initializer)))
+        .collect::<Vec<_>>();
+    if defaults.is_empty() {
+        return Vec::new();
+    }
+    ["".to_string(), "# Defaults".to_string(), "".to_string()].into_iter()
+        .chain(defaults)
+        .collect()
+}
+
+/// Line describing a field, like ```- `age` (`u8`): Age of the character.```.
+fn field_line(field_name: &Ident, field_type: &str, field_doc: &str) -> String {
+    match field_doc.is_empty() {
+        true => format!("- `{field_name}` (`{field_type}`)."),
+        false => format!("- `{field_name}` (`{field_type}`): {}", field_doc.replace('\n', "\n  ")),
+    }
+}
+
+/// Docs for a field's setter on builders.
+pub(crate) fn setter_docs(field_name: &Ident, field_doc: &str) -> TokenStream {
+    let mut lines = vec![format!("Sets the value of `{field_name}`.")];
+    if !field_doc.is_empty() {
+        lines.extend(["".to_string(), field_doc.to_string()]);
+    }
+    doc_attributes(&lines)
+}
+
+/// Docs for the variant of an error enum telling a field's value couldn't be converted.
+pub(crate) fn conversion_error_variant_docs(field_name: &Ident, field_type: &str) -> TokenStream {
+    doc_attributes(&[format!("`{field_name}` couldn't be converted into `{field_type}`, holding the conversion's error.")])
+}
+
+/// Docs for the variant of a shared error enum telling a field's value couldn't be created.
+pub(crate) fn shared_error_variant_docs(field_name: &Ident) -> TokenStream {
+    doc_attributes(&[format!("`{field_name}` couldn't be converted into its field's type, holding the conversion's error.")])
+}
+
+/// Docs for the variant of an error enum telling a field's value couldn't be parsed.
+pub(crate) fn parsing_error_variant_docs(field_name: &Ident, parsed_type: &str) -> TokenStream {
+    doc_attributes(&[format!("`{field_name}` couldn't be parsed into `{parsed_type}`, holding the parsing's error.")])
+}
+
+/// Docs for the variant of an error enum telling a field's converter failed.
+pub(crate) fn converter_error_variant_docs(field_name: &Ident, converter: &FieldConverter) -> TokenStream {
+    doc_attributes(&[format!("`{field_name}` couldn't be converted through `{}`, holding its error.", pretty_tokens(&converter.function))])
+}
+
+/// Docs for the variant of an error enum telling a field's value didn't pass a check.
+pub(crate) fn check_variant_docs(field_name: &Ident, validation: &FieldValidation) -> TokenStream {
+    let description = match validation {
+        FieldValidation::Range(range) => format!("`{field_name}` isn't in the range `{}`.", pretty_tokens(range)),
+        FieldValidation::NonEmpty => format!("`{field_name}` is empty."),
+        FieldValidation::Length(range) => format!("The length of `{field_name}` isn't in the range `{}`.", pretty_tokens(range)),
+        FieldValidation::With(predicate) => format!("`{field_name}` was rejected by `{}`.", pretty_tokens(predicate)),
+    };
+    doc_attributes(&[description])
+}
+
+/// Docs for the variant of an error enum telling the built struct was rejected by its validator.
+pub(crate) fn validation_variant_docs(struct_name: &Ident, validator: &Validator) -> TokenStream {
+    doc_attributes(&[format!("The built [`{struct_name}`] was rejected by `{}`, holding its error.", pretty_tokens(&validator.function))])
+}
@@ -1,148 +1,209 @@
 use proc_macro::TokenStream;
 use std::collections::HashMap;
-use std::str::FromStr;
-use convert_case::Casing;
+use convert_case::{Case, Casing};
 use proc_macro2::Ident;
-use quote::{quote, ToTokens};
-use syn::{Data, DataEnum, DeriveInput, parse_macro_input};
-use syn::parse::Parse;
-use parsing_structs::{FieldsInfo, TryFromInfo};
+use quote::{format_ident, quote, ToTokens};
+use syn::{Data, DataEnum, DataStruct, DeriveInput, GenericParam, Generics, parse_macro_input, parse_quote};
+use parsing_structs::{BuilderInfo, ConstructorInfo, ErrorsMode, SharedErrorEnum, FieldConversion, FieldConverter, FieldsInfo, FieldValidation, TryFromInfo, Validator};
 use crate::utils::{ExpectElseOption, ExpectElseResult, print_info};
 
 mod utils;
+mod docs;
 
 /// Allows you to define a constructor function, inside the proc attribute you can customize the
 /// implementation by giving this information following attributes (Note every attribute is
-/// optional):
+/// optional), the [crate-level documentation](https://docs.rs/derive_constructors) details each of
+/// them along with examples:
 ///
-/// - named: Name of the function, constructor functions are usually named like
-/// 'with_*name of the fields*', as calling them are quite readable, like
-/// ```CharacterInfo::with_name_and_age("Jorge", 23)```. <br> Note: If this field isn't given,
-/// instead of implementing a 'with_*' constructor function, it will implement the [From] or
-/// [TryFrom] trait.
-///
-/// - pattern (values: [From, TryFrom], default: From):
-///     - When using the From pattern, the function receives fields as parameters and returns this
-/// struct with said values, this is what you'll be looking for most of the time.
-///     - When using the TryFrom pattern, the functions receives types that implement
-/// Into<YourField1>, Into<YourField2>..., returning a [Ok] with your struct if every field could
-/// successfully be turned to your field, in case not, it will return [Err] with an enum telling
-/// which field couldn't get initialized and the Error why it didn't, see examples below for this.
-///
-/// - fields (default: All fields not included in the '```defaults```' attribute): Name of the
-/// fields you want to create your constructor for, for example: ```fields(age, name)``` could
-/// result in a function like: ```fn new(age: u8, name: String) -> CharacterInfo```.
-///
-/// - defaults: Tells how to initialize fields not covered in the ```fields``` attribute, for
-/// example ```defaults(years_studied(4))```. <br>If a field isn't either on the ```fields``` or
-/// ```defaults``` attributes, it would count as it was initialized through [Default::default], this
-/// means, the ```times_appeared``` field that hasn't been covered will be init as 0 (since
-/// u8::default() is 0).
-///
-/// - error_enum_named (Only for the TryFrom pattern): Specifies the name for the enum error that
-/// it's returned the TryFrom function fails.
-///
-/// - error_enum_metadata (Only for the TryFrom pattern): Declares the metadata for the enum error
-/// that it's returned the TryFrom function fails, you will most likely want to write
-/// ```error_enum_metadata(#[derive(Debug)])``` in there.
-/// <br><br>
-///
-/// ## 2.1 Example 1: Empty constructor
-///
-/// If you just apply the [constructor] attribute, it will just implement the [From] trait where it
-/// will take a tuple formed out of all your fields, in this case,
-/// ```from(value: (String, u8)) -> CharacterInfo```.
-///
-/// ``` rust
-/// #[derive(Debug, PartialEq)]
-/// #[derive_constructors_proc::constructor]
-/// struct CharacterInfo{
-///     name: String,
-///     age: u8,
-/// }
-///
-/// let character_using_from = CharacterInfo::from(("Jorge".to_string(), 23));
-/// let expected_character = CharacterInfo { name: "Jorge".to_string(), age: 23 };
-/// assert_eq!(character_using_from, expected_character);
-/// ```
-/// <br>
-///
-/// ## 2.2 Example 2: A 'new' constructor using specific fields
-///
-/// The following example creates a function named ```new(name: String, age: u8) -> CharacterInfo```
-/// .<br><br>
-/// Since ```years_studied``` is specified, it will be initialized as 4, and since
-/// ```times_appeared``` is not, it will be initialized as u8::default() (which is 0).
+/// - named: Name of the function, like ```named(new)```, or ```named(auto)``` to name it after the
+///   fields it receives, like ```with_name_and_age```, along with ```named_prefix``` and
+///   ```named_joiner```, replacing ```with``` and ```and```. Without it, [From] or [TryFrom] is
+///   implemented instead.
+/// - pattern (values: [From, TryFrom, Into, Builder, TypestateBuilder, FromStr], default: From):
+///   How the constructor receives and converts its values, where the TryFrom and FromStr patterns
+///   return an error enum telling which field failed, and why.
+/// - fields (default: All fields not included in ```defaults```): Fields the constructor receives,
+///   like ```fields(name, age)```, whose types might be declared, like ```fields(age: u16)```.
+/// - defaults: Values of the fields not received, like ```defaults(years_studied(4))```, otherwise
+///   given by ```#[no_from(value)]``` or [Default::default].
+/// - convert: Functions building fields out of another type, like
+///   ```convert(timeout(with = Duration::from_millis, from = u64))```.
+/// - from_unit (a flag): Implements [From] for ```()``` when there are no fields to receive.
+/// - parts (a flag): Adds ```into_parts``` and ```as_parts```, splitting your struct back into the
+///   values its constructor receives.
+/// - validate: Function checking your struct once it's built along with the type of its error, like
+///   ```validate(check_age, AgeError)```, while fields declare checks like
+///   ```#[validate(range(0..150))]```.
+/// - const (a flag): Makes the constructor a ```const fn```.
+/// - doc: Summary documenting the constructor function, like ```doc("Creates a playable character.")```.
+/// - vis (default: pub): Visibility of the constructor function, like ```vis(pub(crate))```.
+/// - error_enum_vis (default: the one given in ```vis```): Visibility of the error enum.
+/// - fn_attrs: Attributes written on the constructor function, like ```fn_attrs(#[must_use])```.
+/// - impl_attrs: Attributes written on the impl blocks holding the constructor.
+/// - from_strs_named (Only for the FromStr pattern): Name of the function taking a row, by default,
+///   ```from_strs```.
+/// - builder_named (Only for the Builder and TypestateBuilder patterns): Name of the builder, by
+///   default, ```{YourStruct}Builder```.
+/// - error_enum_named (Only for the TryFrom, Builder and FromStr patterns): Name of the error enum.
+/// - error_enum_metadata (Only for the TryFrom, Builder and FromStr patterns): Metadata of the error
+///   enum, like ```error_enum_metadata(#[derive(Debug)])```.
+/// - error_variants_named (Only for the TryFrom and FromStr patterns): Template naming the variants
+///   holding the error of each field, like ```error_variants_named("Invalid{Field}")```.
+/// - error_enum (Only for the TryFrom pattern): Shares an error enum between several constructors,
+///   through ```error_enum(define = CharacterInfoError)``` and ```error_enum(use = CharacterInfoError)```.
+/// - error_enum_impls (a flag, only for the TryFrom and FromStr patterns): Implements
+///   [Display](std::fmt::Display) and [Error](std::error::Error) for the error enum.
+/// - errors (only for the TryFrom pattern): ```errors(collect)``` returns every error found, and
+///   ```errors(return_inputs)``` returns the values received along with the error.
 ///
 /// ``` rust
 /// #[derive(Debug, PartialEq)]
 /// #[derive_constructors_proc::constructor(named(new), fields(name, age), defaults(years_studied(4)))]
-/// struct CharacterInfo{
+/// struct CharacterInfo {
 ///     name: String,
 ///     age: u8,
-///     times_appeared: u8,
-///     years_studied: u8
+///     years_studied: u8,
 /// }
 ///
-/// let character_using_from = CharacterInfo::new("Jorge".to_string(), 23);
-/// let expected_character = CharacterInfo { name: "Jorge".to_string(), age: 23, times_appeared: 0, years_studied: 4};
-/// assert_eq!(character_using_from, expected_character);
+/// let character = CharacterInfo::new("Jorge".to_string(), 23);
+/// assert_eq!(character, CharacterInfo { name: "Jorge".to_string(), age: 23, years_studied: 4 });
 /// ```
-/// <br>
-///
-/// ## 2.3 Example 3: A 'new' constructor with the TryFrom pattern
-///
-/// The following example creates a function named ```new(name: T where String: TryFrom<T>, age: U
-/// where String: TryFrom<U>) -> Result<CharacterInfo, MyEnumError>```.<br><br>
-/// Since ```years_studied``` is specified, it will be initialized as 4, and since
-/// ```times_appeared``` is not, it will be initialized as u8::default() (which is 0).<br><br>
-/// In case of an error, it returns a variant of an enum named ```MyEnumError```, this enum is
-/// specified to derive [Debug] and [PartialEq].
+#[proc_macro_attribute]
+pub fn constructor(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut derive_input = parse_macro_input!(item as DeriveInput);
+    let data = match derive_input.data.clone() {
+        Data::Struct(data) => data,
+        _ => panic!("This attribute macro is only implemented for structs"),
+    };
+
+    let attr_contents = utils::idents_and_groups_from(attr.clone())
+        .expect_else(|_| "Could not resolve groups and descriptions")
+        .into_iter()
+        .map(|(ident, group)| (ident.to_token_stream().to_string(), group))
+        .collect::<HashMap<_, _>>();
+
+    check_parts_asked_once(&derive_input, usize::from(attr_contents.contains_key("parts")));
+    let ex = tokens_for__constructor(&derive_input, &data, attr_contents);
+
+    utils::strip_helper_attributes(&mut derive_input);
+    let mut item = TokenStream::from(derive_input.into_token_stream());
+    item.extend(ex);
+    item
+}
+
+/// Allows you to define several constructor functions at once, where each entry is a constructor
+/// named after the entry that takes the same attributes as the [constructor] attribute, while the
+/// attributes written outside of any entry, like ```defaults``` or ```error_enum_metadata```, are
+/// shared by every entry, where entries named ```auto``` are named just like with
+/// ```named(auto)```.<br>
+/// When both the shared attributes and an entry give the same attribute, the entry's is used,
+/// except for ```defaults```, which are merged, where the entry's default for a field replaces the
+/// shared one, and the shared defaults of the fields the entry receives as parameters are ignored.
 ///
 /// ``` rust
-/// let character_using_try_from = CharacterInfo::new("Jorge", 23_u16).unwrap();
-/// let expected_character = CharacterInfo { name: "Jorge".to_string(), age: 23, times_appeared: 0, years_studied: 4};
-/// assert_eq!(character_using_try_from, expected_character);
-///
-/// let produced_error = u8::try_from(23000_u16).unwrap_err();
-/// let forced_error_using_try_from = CharacterInfo::new("Jorge", 23000_u16).unwrap_err();
-/// let expected_error_on_try_from = MyEnumError::AgeError(produced_error);
-/// assert_eq!(forced_error_using_try_from, expected_error_on_try_from);
-///
 /// #[derive(Debug, PartialEq)]
-/// #[derive_constructors_proc::constructor(
-///     named(new),
-///     fields(name, age),
-///     defaults(years_studied(4)),
-///     pattern(TryFrom),
-///     error_enum_named(MyEnumError),
-///     error_enum_metadata(#[derive(Debug, PartialEq)])
+/// #[derive_constructors_proc::constructors(
+///     new(fields(name, age)),
+///     with_id(fields(id), defaults(age(0))),
+///     try_new(pattern(TryFrom), fields(name, age), error_enum_named(TryNewError)),
+///     defaults(id(1), name("Unknown".to_string())),
+///     error_enum_metadata(#[derive(Debug)]),
 /// )]
 /// struct CharacterInfo{
+///     id: u32,
 ///     name: String,
 ///     age: u8,
-///     times_appeared: u8,
-///     years_studied: u8,
 /// }
+///
+/// assert_eq!(CharacterInfo::new("Jorge".to_string(), 23), CharacterInfo { id: 1, name: "Jorge".to_string(), age: 23 });
+/// assert_eq!(CharacterInfo::with_id(7), CharacterInfo { id: 7, name: "Unknown".to_string(), age: 0 });
+/// assert_eq!(CharacterInfo::try_new("Jorge", 23_u16).unwrap(), CharacterInfo { id: 1, name: "Jorge".to_string(), age: 23 });
 /// ```
 #[proc_macro_attribute]
-pub fn constructor(attr: TokenStream, mut item: TokenStream) -> TokenStream {
-    let item_cloned = item.clone();
-    let derive_input = parse_macro_input!(item_cloned as DeriveInput);
+pub fn constructors(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut derive_input = parse_macro_input!(item as DeriveInput);
     let data = match derive_input.data.clone() {
         Data::Struct(data) => data,
         _ => panic!("This attribute macro is only implemented for structs"),
     };
 
-    let mut attr_contents = utils::idents_and_groups_from(attr.clone())
+    let (shared_options, entries) = utils::idents_and_groups_from(attr.clone())
         .expect_else(|_| "Could not resolve groups and descriptions")
         .into_iter()
-        .map(|(ident, group)| (ident.to_string(), group))
-        .collect::<HashMap<_, _>>();
+        .map(|(ident, group)| (ident.to_token_stream().to_string(), group))
+        .partition::<Vec<_>, _>(|(name, _)| CONSTRUCTOR_OPTIONS.contains(&name.as_str()));
+    check_parts_asked_once(&derive_input, parts_asked_by_entries(&shared_options.iter().chain(entries.iter()).cloned().collect::<Vec<_>>()));
+    let shared_options = shared_options.into_iter().collect::<HashMap<_, _>>();
 
-    let constructor_fn_name = attr_contents.remove("named")
-        .map(|constructor_name| syn::parse::<Ident>(constructor_name.into())
-            .expect_else(|_| "Could not get name for constructor's function"));
+    let ex = entries.into_iter()
+        .map(|(constructor_name, entry)| {
+            let entry_options = utils::idents_and_groups_from(entry)
+                .expect_else(|_| format!("Could not resolve groups and descriptions of constructor '{constructor_name}'"))
+                .into_iter()
+                .map(|(ident, group)| (ident.to_token_stream().to_string(), group))
+                .collect::<HashMap<_, _>>();
+            if let Some(wrong_option) = entry_options.keys().find(|option| !CONSTRUCTOR_OPTIONS.contains(&option.as_str())) {
+                panic!("Constructor '{constructor_name}' is given an attribute named '{wrong_option}', which doesn't exist")
+            }
+            if entry_options.contains_key("named") {
+                panic!("Constructor '{constructor_name}' is already named after its entry, consider removing its 'named' attribute")
+            }
+            let mut attr_contents = shared_options.clone();
+            attr_contents.insert("named".to_string(), constructor_name.parse().unwrap());
+            let shared_defaults = attr_contents.remove("defaults");
+            attr_contents.extend(entry_options);
+            if let Some(defaults) = merged_defaults(shared_defaults, &attr_contents) {
+                attr_contents.insert("defaults".to_string(), defaults);
+            }
+            tokens_for__constructor(&derive_input, &data, attr_contents)
+        })
+        .collect::<TokenStream>();
+
+    utils::strip_helper_attributes(&mut derive_input);
+    let mut item = TokenStream::from(derive_input.into_token_stream());
+    item.extend(ex);
+    item
+}
+
+/// Attributes taken by a single constructor, as given to the [constructor] attribute or to an entry
+/// of the [constructors] attribute.
+const CONSTRUCTOR_OPTIONS: &[&str] = &[
+    "named", "named_prefix", "named_joiner", "pattern", "vis", "error_enum_vis", "const", "doc", "fn_attrs", "impl_attrs", "from_strs_named", "fields", "defaults", "convert", "from_unit", "validate",
+    "builder_named", "error_enum_named", "error_enum_metadata", "error_enum", "error_enum_impls", "errors", "error_variants_named", "parts",
+];
+
+/// Defaults for an entry of the [constructors] attribute, these are the entry's defaults plus the
+/// shared ones for the fields the entry neither defaults nor receives as parameters.
+fn merged_defaults(shared_defaults: Option<proc_macro2::TokenStream>, attr_contents: &HashMap<String, proc_macro2::TokenStream>) -> Option<proc_macro2::TokenStream> {
+    let Some(shared_defaults) = shared_defaults else {
+        return attr_contents.get("defaults").cloned();
+    };
+    let parse_members = |tokens: Option<&proc_macro2::TokenStream>| tokens
+        .map(|tokens| utils::idents_and_groups_from(tokens.clone())
+            .expect_else(|_| "Could not resolve groups and descriptions inside attribute 'defaults'"))
+        .unwrap_or_default();
+    let entry_defaults = parse_members(attr_contents.get("defaults"));
+    let entry_fields = attr_contents.get("fields")
+        .map(|fields| utils::fields_of_option(fields.clone()).into_iter()
+            .map(|(member, _)| member)
+            .collect::<Vec<_>>())
+        .unwrap_or_default();
+    let inherited_defaults = parse_members(Some(&shared_defaults)).into_iter()
+        .filter(|(member, _)| !entry_fields.contains(member) && !entry_defaults.iter().any(|(entry_member, _)| entry_member == member))
+        .collect::<Vec<_>>();
+    let defaults = inherited_defaults.into_iter()
+        .chain(entry_defaults)
+        .map(|(member, default)| quote!(#member(#default)));
+    Some(quote!(#(#defaults),*))
+}
+
+/// Implementation of a single constructor, as asked by the [constructor] attribute or by an entry of
+/// the [constructors] attribute.
+#[allow(non_snake_case)]
+fn tokens_for__constructor(derive_input: &DeriveInput, data: &DataStruct, mut attr_contents: HashMap<String, proc_macro2::TokenStream>) -> TokenStream {
+    let constructor_fn_name = attr_contents.remove("named");
+    let named_prefix = attr_contents.remove("named_prefix").map(|prefix| prefix.to_string());
+    let named_joiner = attr_contents.remove("named_joiner").map(|joiner| joiner.to_string());
 
     let constructor_pattern = attr_contents.remove("pattern")
         .map(|pattern| pattern.to_string().to_lowercase())
@@ -150,29 +211,147 @@ pub fn constructor(attr: TokenStream, mut item: TokenStream) -> TokenStream {
     let constructor_pattern = match constructor_pattern.as_str() {
         "from" => Pattern::From,
         "tryfrom" => Pattern::TryFrom,
-        wrong_pattern => panic!("This constructor is asking for a pattern by the name of '{wrong_pattern}', the only patterns available are 'From' and 'TryFrom' ")
+        "into" => Pattern::Into,
+        "builder" => Pattern::Builder,
+        "typestatebuilder" => Pattern::TypestateBuilder,
+        "fromstr" => Pattern::FromStr,
+        wrong_pattern => panic!("This constructor is asking for a pattern by the name of '{wrong_pattern}', the only patterns available are 'From', 'TryFrom', 'Into', 'Builder', 'TypestateBuilder' and 'FromStr' ")
     };
 
-    let fields_info = FieldsInfo::new_from_macro_attribute_info(&data, &mut attr_contents);
+    let implements_for_unit = attr_contents.remove("from_unit").is_some();
+    let implements_parts = attr_contents.remove("parts").is_some();
+    let declares_input_types = attr_contents.get("fields")
+        .is_some_and(|fields| utils::fields_of_option(fields.clone()).iter().any(|(_, input_type)| input_type.is_some()));
+    if declares_input_types && !matches!(constructor_pattern, Pattern::TryFrom) {
+        panic!("Only the TryFrom pattern receives fields as other types, consider removing the types declared in 'fields', as in 'fields(age)' rather than 'fields(age: u16)'")
+    }
 
-    let ex = match constructor_pattern {
+    let constructor_info = ConstructorInfo::new_from_macro_attribute_info(derive_input, &mut attr_contents);
+    let fields_info = FieldsInfo::new_from_macro_attribute_info(data, &derive_input.generics, &mut attr_contents);
+    let is_auto_named = constructor_fn_name.as_ref().is_some_and(|constructor_name| constructor_name.to_string() == "auto");
+    if let Some(naming_option) = [("named_prefix", &named_prefix), ("named_joiner", &named_joiner)].into_iter()
+        .find_map(|(naming_option, value)| (value.is_some() && !is_auto_named).then_some(naming_option)) {
+        panic!("The '{naming_option}' attribute only applies to constructors named through 'named(auto)', consider removing it or using 'named(auto)'")
+    }
+    let constructor_fn_name = constructor_fn_name
+        .map(|constructor_name| match constructor_name.to_string().as_str() {
+            "auto" => auto_constructor_fn_name(&constructor_pattern, &fields_info.fields_names, named_prefix, named_joiner),
+            _ => syn::parse::<Ident>(constructor_name.into())
+                .expect_else(|_| "Could not get name for constructor's function"),
+        });
+
+    if constructor_info.is_const {
+        check_const_constructor(&constructor_pattern, constructor_fn_name.as_ref(), &constructor_info, &fields_info);
+    }
+
+    let implements_trait = matches!(constructor_pattern, Pattern::From | Pattern::TryFrom) && constructor_fn_name.is_none();
+    if implements_trait && fields_info.fields_names.is_empty() && !implements_for_unit {
+        return TokenStream::new();
+    }
+    let parts = implements_parts.then(|| tokens_for__parts(&derive_input.ident, &derive_input.generics, &fields_info, &constructor_info));
+    let constructor = match constructor_pattern {
         Pattern::From => {
-            tokens_for__from__for_struct(derive_input.ident, fields_info, constructor_fn_name)
+            tokens_for__from__for_struct(derive_input.ident.clone(), derive_input.generics.clone(), fields_info, constructor_info, constructor_fn_name)
         }
         Pattern::TryFrom => {
-            let try_from_info = TryFromInfo::new_from_macro_attribute_info(&derive_input, &fields_info, constructor_fn_name.as_ref(), &mut attr_contents);
-            tokens_for__try_from__for_struct(derive_input.ident, fields_info, try_from_info, constructor_fn_name)
+            let try_from_info = TryFromInfo::new_from_macro_attribute_info(derive_input, &fields_info, constructor_fn_name.as_ref(), false, &mut attr_contents);
+            tokens_for__try_from__for_struct(derive_input.ident.clone(), derive_input.generics.clone(), fields_info, try_from_info, constructor_info, constructor_fn_name)
+        }
+        Pattern::Into => {
+            let constructor_fn_name = constructor_fn_name
+                .expect_else(|| "The Into pattern creates a constructor function, so it requires a name for it, for example, 'named(new)'");
+            tokens_for__into__for_struct(derive_input.ident.clone(), derive_input.generics.clone(), fields_info, constructor_info, constructor_fn_name)
+        }
+        Pattern::Builder => {
+            let constructor_fn_name = constructor_fn_name.unwrap_or_else(|| format_ident!("builder"));
+            let builder_info = BuilderInfo::new_from_macro_attribute_info(derive_input, &fields_info, &mut attr_contents);
+            tokens_for__builder__for_struct(derive_input.ident.clone(), derive_input.generics.clone(), fields_info, builder_info, constructor_info, constructor_fn_name)
+        }
+        Pattern::TypestateBuilder => {
+            let constructor_fn_name = constructor_fn_name.unwrap_or_else(|| format_ident!("builder"));
+            if let Some(error_enum_option) = ["error_enum_named", "error_enum_metadata"].into_iter().find(|option| attr_contents.contains_key(*option)) {
+                panic!("The TypestateBuilder pattern has no error enum, as missing fields are caught at compile time, consider removing '{error_enum_option}'")
+            }
+            let builder_info = BuilderInfo::new_from_macro_attribute_info(derive_input, &fields_info, &mut attr_contents);
+            tokens_for__typestate_builder__for_struct(derive_input.ident.clone(), derive_input.generics.clone(), fields_info, builder_info, constructor_info, constructor_fn_name)
+        }
+        Pattern::FromStr => {
+            let constructor_fn_name = constructor_fn_name.unwrap_or_else(|| format_ident!("parse"));
+            let from_strs_fn_name = attr_contents.remove("from_strs_named")
+                .map(|name| syn::parse::<Ident>(name.into())
+                    .expect_else(|_| "Could not get name for the function parsing rows, given through 'from_strs_named'"))
+                .unwrap_or_else(|| format_ident!("from_strs"));
+            let try_from_info = TryFromInfo::new_from_macro_attribute_info(derive_input, &fields_info, Some(&constructor_fn_name), true, &mut attr_contents);
+            tokens_for__from_str__for_struct(derive_input.ident.clone(), derive_input.generics.clone(), fields_info, try_from_info, constructor_info, constructor_fn_name, from_strs_fn_name)
         }
     };
+    let constructor = proc_macro2::TokenStream::from(constructor);
+    quote!(#constructor #parts).into()
+}
 
+/// Name for a constructor given ```named(auto)```, this is, the prefix followed by the fields' names
+/// separated by the joiner, like ```with_name_and_age```, where the prefix is ```with``` and the
+/// joiner is ```and``` unless given through ```named_prefix``` and ```named_joiner```.<br>
+/// With the TryFrom pattern the prefix starts with ```try_```, as in ```try_with_name_and_age```,
+/// while a constructor without fields is simply named ```new``` or ```try_new```, and with the
+/// FromStr pattern it starts with ```parse_```, as in ```parse_with_name_and_age``` or
+/// ```parse_new```.
+fn auto_constructor_fn_name(pattern: &Pattern, fields_names: &[Ident], prefix: Option<String>, joiner: Option<String>) -> Ident {
+    let prefix = prefix.unwrap_or_else(|| "with".to_string());
+    let joiner = joiner.unwrap_or_else(|| "and".to_string());
+    let try_prefix = match pattern {
+        Pattern::TryFrom => Some("try"),
+        Pattern::FromStr => Some("parse"),
+        _ => None,
+    };
+    let name_parts = match fields_names.is_empty() {
+        true => vec!["new".to_string()],
+        false => [prefix].into_iter()
+            .chain(fields_names.iter().enumerate()
+                .flat_map(|(index, field_name)| [(index > 0).then(|| joiner.clone()), Some(field_name.to_string())])
+                .flatten())
+            .collect(),
+    };
+    let name = try_prefix.map(ToString::to_string).into_iter().chain(name_parts)
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_");
+    syn::parse_str::<Ident>(&name)
+        .expect_else(|_| format!("Could not create the constructor's name '{name}', consider giving it through 'named'"))
+}
 
-    item.extend(Into::<TokenStream>::into(ex));
-    item
+/// Panics if the error type of the validator uses the struct's generics, as it's held by the
+/// ```ValidationError``` variant of an error enum, which doesn't take them.
+fn check_validator_error_type(validator: Option<&Validator>, generics: &Generics) {
+    if let Some(validator) = validator.filter(|validator| utils::type_uses_generics_or_lifetimes(&validator.error_type, generics)) {
+        panic!("The error type '{}' given through 'validate' uses the struct's generics, but it's held by the error enum, which doesn't take them, consider using an error type that doesn't depend on them, or the From pattern, which returns it as it is",
+               utils::pretty_tokens(&validator.error_type))
+    }
+}
+
+/// Panics if a constructor asking to be a ```const fn``` can't be one, this is, if it isn't a
+/// function of the From pattern, it has a validator, or some field would be initialized through
+/// [Default::default], which isn't const.
+fn check_const_constructor(pattern: &Pattern, constructor_fn_name: Option<&Ident>, constructor_info: &ConstructorInfo, fields_info: &FieldsInfo) {
+    if !matches!(pattern, Pattern::From) || constructor_fn_name.is_none() {
+        panic!("Only constructor functions of the From pattern can be const, consider removing 'const' or giving the constructor a name, like 'named(new)', and using 'pattern(From)'")
+    }
+    if constructor_info.validator.is_some() {
+        panic!("Const constructors can't use 'validate', consider removing 'const' or 'validate'")
+    }
+    if let Some(defaulted_field) = fields_info.default_initialized_fields.first() {
+        let defaulted_field = defaulted_field.to_token_stream();
+        panic!("Field '{defaulted_field}' would be initialized through Default::default(), which can't be called on a const fn, consider giving it a const default through 'defaults({defaulted_field}(value))' or '#[no_from(value)]'")
+    }
 }
 
 enum Pattern {
     From,
     TryFrom,
+    Into,
+    Builder,
+    TypestateBuilder,
+    FromStr,
 }
 
 /// On structs it allows to Derive the [From] trait where a tuple of the fields are passed to the
@@ -193,6 +372,15 @@ enum Pattern {
 /// let expected_character = CharacterInfo { name: "Jorge".to_string(), age: 23, times_appeared: 0, years_studied: 4};
 /// assert_eq!(character_using_from, expected_character);
 /// ```
+///
+/// Structs with no fields to receive, like unit structs, don't implement ```From<()>``` unless
+/// they are marked with the ```#[from_unit]``` attribute, while unit variants of enums always
+/// implement it.
+///
+/// On structs, ```#[fn_attrs(...)]``` and ```#[impl_attrs(...)]``` give attributes for the
+/// ```from``` function and its impl block, like ```#[fn_attrs(#[inline])]```, while the ```#[parts]```
+/// attribute splits structs back into the values of the fields received, just like the ```parts```
+/// option of ```#[constructor]```.
 /// <br><br>
 /// 
 /// On enums it implement the [From] trait by creating a From::from function for each variant taking
@@ -210,17 +398,121 @@ enum Pattern {
 /// let specified = vec![MyValue::StaticString("Age "), MyValue::Number(23), MyValue::StaticString(", over age "), MyValue::Boolean(true)];
 /// assert_eq!(scattered_values, specified);
 /// ```
-#[proc_macro_derive(From, attributes(no_from))]
+#[proc_macro_derive(From, attributes(no_from, from_unit, into, try_from, exact, convert, validate, error_variant, fn_attrs, impl_attrs, parts))]
 pub fn derive_from(input: TokenStream) -> TokenStream {
     /*    let cloned_input = input.clone();
     let p = format!("{:#?}\n", parse_macro_input!(cloned_input as DeriveInput));
     print_info(|| "Derive input info", || p);*/
-    let DeriveInput { ident, data, .. } = parse_macro_input!(input as DeriveInput);
+    let DeriveInput { ident, data, attrs, generics, vis, .. } = parse_macro_input!(input as DeriveInput);
     match data {
         Data::Union(_) => panic!("The 'From' derive_constructors_proc macro targets structs and enums, consider removing '#[derive_constructors_proc(From)]' for this type"),
-        Data::Struct(data_struct) => return tokens_for__from__for_struct(ident, FieldsInfo::new_from_derive_data_struct(&data_struct), None),
-        Data::Enum(data_enum) => return tokens_for__from__for_enum(ident, data_enum),
-    };
+        Data::Struct(data_struct) => {
+            if utils::find_attribute(&attrs, "validate").is_some() {
+                panic!("The #[validate] attribute makes constructing '{ident}' fallible, which [From] can't be, consider deriving 'TryFrom' instead")
+            }
+            let fields_info = FieldsInfo::new_from_derive_data_struct(&data_struct, &generics);
+            if fields_info.fields_names.is_empty() && utils::find_attribute(&attrs, "from_unit").is_none() {
+                return TokenStream::new();
+            }
+            let constructor_info = ConstructorInfo::new_from_derive_data_struct(&attrs, &vis);
+            let parts = utils::find_attribute(&attrs, "parts").is_some()
+                .then(|| tokens_for__parts(&ident, &generics, &fields_info, &constructor_info));
+            let from = proc_macro2::TokenStream::from(tokens_for__from__for_struct(ident, generics, fields_info, constructor_info, None));
+            quote!(#from #parts).into()
+        }
+        Data::Enum(data_enum) => tokens_for__from__for_enum(ident, generics, data_enum),
+    }
+}
+
+/// Number of constructors asking for ```parts``` among the attributes left on the struct, these are
+/// the ```#[parts]``` attribute of the From derive, and the ```parts``` option of the
+/// ```#[constructor]``` attributes and ```#[constructors]``` entries yet to be expanded.
+fn parts_asked_by(attrs: &[syn::Attribute]) -> usize {
+    let options_of = |tokens: proc_macro2::TokenStream| utils::idents_and_groups_from(tokens)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(option, group)| (option.to_token_stream().to_string(), group))
+        .collect::<Vec<_>>();
+    attrs.iter()
+        .map(|attribute| {
+            let attribute_name = attribute.path.segments.last().map(|segment| segment.ident.to_string()).unwrap_or_default();
+            let options = || options_of(attribute.parse_args::<proc_macro2::TokenStream>().unwrap_or_default());
+            match attribute_name.as_str() {
+                "parts" => 1,
+                "constructor" => options().iter().filter(|(option, _)| option == "parts").count(),
+                "constructors" => parts_asked_by_entries(&options()),
+                _ => 0,
+            }
+        })
+        .sum()
+}
+
+/// Number of entries of a ```#[constructors]``` attribute asking for ```parts```, which is every
+/// entry when it's given as a shared option.
+fn parts_asked_by_entries(options: &[(String, proc_macro2::TokenStream)]) -> usize {
+    let (shared_options, entries) = options.iter()
+        .partition::<Vec<_>, _>(|(name, _)| CONSTRUCTOR_OPTIONS.contains(&name.as_str()));
+    if shared_options.iter().any(|(option, _)| option == "parts") {
+        return entries.len();
+    }
+    entries.iter()
+        .filter(|(_, entry)| utils::idents_and_groups_from(entry.clone())
+            .is_ok_and(|entry_options| entry_options.iter().any(|(option, _)| option.to_token_stream().to_string() == "parts")))
+        .count()
+}
+
+/// Panics when ```parts``` is asked for more than once, counting the ```asked_here``` times it's
+/// asked by the attribute being expanded and the times the rest of the struct's attributes ask for
+/// it, as said functions would be defined twice.
+fn check_parts_asked_once(derive_input: &DeriveInput, asked_here: usize) {
+    if asked_here > 0 && asked_here + parts_asked_by(&derive_input.attrs) > 1 {
+        panic!("Struct {} asks for 'parts' more than once, which would define 'into_parts' and 'as_parts' twice, consider removing 'parts' from all but one constructor", derive_input.ident)
+    }
+}
+
+/// Functions splitting the struct into the values of the fields its constructor receives, as asked
+/// through the ```parts``` option or the ```#[parts]``` attribute, these are ```into_parts```,
+/// ```as_parts``` and the implementation of [From] for the tuple of said values, where the latter
+/// isn't implemented when there is a single value whose type has an uncovered type parameter, like
+/// ```T``` or ```&'a T```, as Rust doesn't allow implementing foreign traits for them, see
+/// [utils::has_uncovered_type_parameter].<br>
+/// As ```into_parts``` moves the fields out of the struct, it can't implement [Drop].
+#[allow(non_snake_case)]
+fn tokens_for__parts(name: &Ident, generics: &Generics, fields_info: &FieldsInfo, constructor_info: &ConstructorInfo) -> proc_macro2::TokenStream {
+    let FieldsInfo { fields_members, fields_types, .. } = fields_info;
+    let ConstructorInfo { vis, impl_attrs, cfg_attrs, .. } = constructor_info;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let fields_list = fields_members.iter()
+        .map(|member| format!("`{}`", member.to_token_stream()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let into_parts_docs = docs::doc_attributes(&[format!("Splits this [`{name}`] into the values of {fields_list}, in the order its constructor receives them.")]);
+    let as_parts_docs = docs::doc_attributes(&[format!("References to the values of {fields_list}, in the order this [`{name}`]'s constructor receives them.")]);
+    let implements_from = !matches!(&fields_types[..], [field_type] if utils::has_uncovered_type_parameter(field_type, generics));
+    let from_impl = implements_from.then(|| quote! {
+        #cfg_attrs #impl_attrs
+        impl #impl_generics core::convert::From<#name #ty_generics> for (#(#fields_types),*) #where_clause {
+            fn from(value: #name #ty_generics) -> Self {
+                value.into_parts()
+            }
+        }
+    });
+    quote! {
+        #cfg_attrs #impl_attrs
+        impl #impl_generics #name #ty_generics #where_clause {
+            #into_parts_docs
+            #vis fn into_parts(self) -> (#(#fields_types),*) {
+                (#(self.#fields_members),*)
+            }
+
+            #as_parts_docs
+            #vis fn as_parts(&self) -> (#(&#fields_types),*) {
+                (#(&self.#fields_members),*)
+            }
+        }
+
+        #from_impl
+    }
 }
 
 /// It derives [TryFrom] trait where a tuple of this struct's fields are passed to the
@@ -247,68 +539,793 @@ pub fn derive_from(input: TokenStream) -> TokenStream {
 /// let expected_error_on_try_from = CharacterInfoTryFromError::AgeError(produced_error);
 /// assert_eq!(forced_error_using_try_from, expected_error_on_try_from);
 /// ```
-#[proc_macro_derive(TryFrom, attributes(no_from, enum_error_meta))]
+///
+/// A ```#[validate(path::to::function, ErrorType)]``` attribute on the struct checks it once it's
+/// built, returning the function's error as the ```ValidationError``` variant of the error enum,
+/// while fields might declare checks like ```#[validate(range(0..150), non_empty, len(1..=32),
+/// with = path::to::predicate)]```, see the [constructor] attribute's ```validate``` option.<br>
+/// The error enum is public unless given a visibility like ```#[error_enum_vis(pub(crate))]```,
+/// where ```#[error_enum_vis(inherit)]``` takes your struct's visibility.
+///
+/// Just like on the From derive, ```#[fn_attrs(...)]``` and ```#[impl_attrs(...)]``` give
+/// attributes for the ```try_from``` function and its impl block.
+///
+/// Fields can be marked with ```#[try_from]```, ```#[into]``` or ```#[exact]``` to choose which
+/// values are converted through TryFrom, just like with the TryFrom pattern of ```#[constructor]```,
+/// where ```#[try_from(u16)]``` also declares the type the field is received as.
+///
+/// The ```#[error_enum_impls]``` attribute implements [Display](std::fmt::Display) and
+/// [Error](std::error::Error) for the error enum, just like the ```error_enum_impls``` option of
+/// ```#[constructor]```, while ```#[errors(collect)]``` returns every error found at once, and
+/// ```#[errors(return_inputs)]``` returns the values received along with the error, as the
+/// ```errors``` option does.
+///
+/// Variants of the error enum can be named through ```#[error_variants_named("Invalid{Field}")]```
+/// or ```#[error_variant(InvalidAge)]``` on a field, just like with the ```error_variants_named```
+/// option of ```#[constructor]```, while ```#[error_enum(use = CharacterInfoError)]``` returns the
+/// error enum declared by a constructor through its ```error_enum``` option.
+#[proc_macro_derive(TryFrom, attributes(no_from, from_unit, into, try_from, exact, convert, enum_error_meta, error_enum, error_enum_impls, errors, error_variant, error_variants_named, validate, error_enum_vis, fn_attrs, impl_attrs))]
 pub fn derive_try_from(input: TokenStream) -> TokenStream {
     let cloned_input = input.clone();
     let p = format!("{:#?}\n", parse_macro_input!(cloned_input as DeriveInput));
     print_info(|| "Derive input info", || p);
 
-    let DeriveInput { ident, data, attrs, .. } = parse_macro_input!(input as DeriveInput);
+    let DeriveInput { ident, data, attrs, generics, vis, .. } = parse_macro_input!(input as DeriveInput);
     match data {
         Data::Union(_) | Data::Enum(_) => panic!("The 'From' derive_constructors_proc macro targets structs, consider removing '#[derive_constructors_proc(From)]' for this type"),
         Data::Struct(data_struct) => {
-            let fields_info = FieldsInfo::new_from_derive_data_struct(&data_struct);
-            let try_from_info = TryFromInfo::new_from_derive_data_struct(&ident, &attrs, &fields_info.fields_names);
-            return tokens_for__try_from__for_struct(ident, fields_info, try_from_info, None);
+            let fields_info = FieldsInfo::new_from_derive_data_struct(&data_struct, &generics);
+            if fields_info.fields_names.is_empty() && utils::find_attribute(&attrs, "from_unit").is_none() {
+                return TokenStream::new();
+            }
+            let try_from_info = TryFromInfo::new_from_derive_data_struct(&ident, &attrs, &data_struct, &generics, &fields_info);
+            let constructor_info = ConstructorInfo::new_from_derive_data_struct(&attrs, &vis);
+            tokens_for__try_from__for_struct(ident, generics, fields_info, try_from_info, constructor_info, None)
         }
+    }
+}
+
+
+/// Adds the ```FieldType: Default``` bounds required by the fields initialized through
+/// [Default::default] to the struct's where clause.
+fn generics_with_default_bounds(generics: &Generics, default_bounds: &[syn::Type]) -> Generics {
+    let mut generics = generics.clone();
+    let where_clause = generics.make_where_clause();
+    where_clause.predicates.extend(default_bounds.iter()
+        .map(|default_type| -> syn::WherePredicate { parse_quote!(#default_type : core::default::Default) }));
+    generics
+}
+
+/// Literal of the struct where the fields received as parameters take the values found under their
+/// names from [FieldsInfo::fields_names], while the rest are initialized with their defaults, which
+/// are bound beforehand in the order the fields are declared, so they can use the parameters and
+/// the defaults of the fields declared before them.
+#[allow(non_snake_case)]
+fn tokens_for__struct_literal(struct_path: proc_macro2::TokenStream, fields_info: &FieldsInfo) -> proc_macro2::TokenStream {
+    let FieldsInfo { fields_names, fields_members, no_from_fields, no_from_fields_names, no_from_fields_initializers, .. } = fields_info;
+    quote! {
+        {
+            #(let #no_from_fields_names = #no_from_fields_initializers;)*
+            #struct_path {
+                #(#fields_members: #fields_names,)*
+                #(#no_from_fields: #no_from_fields_names,)*
+            }
+        }
+    }
+}
+
+/// Expression returning the struct literal as [Ok], where if there's a [Validator], the struct is
+/// first checked by it, returning its error wrapped by ```validation_error_variant``` if given,
+/// which might also take the struct, as it's still owned as ```value``` at that point.
+#[allow(non_snake_case)]
+fn tokens_for__validated_struct(struct_literal: proc_macro2::TokenStream, validator: Option<&Validator>, validation_error_variant: Option<proc_macro2::TokenStream>) -> proc_macro2::TokenStream {
+    let Some(Validator { function, .. }) = validator else {
+        return quote!(Ok(#struct_literal));
     };
+    let error = validation_error_variant
+        .map(|variant| quote!((#variant)(error)))
+        .unwrap_or_else(|| quote!(core::convert::From::from(error)));
+    quote! {
+        {
+            let value = #struct_literal;
+            match #function(&value) {
+                core::result::Result::Ok(_) => Ok(value),
+                core::result::Result::Err(error) => Err(#error),
+            }
+        }
+    }
+}
+
+/// Types of the parameters of a constructor, which are the fields' types unless they are given a
+/// [FieldConverter], where they take the converter's input type, along with the statements
+/// converting them.
+fn parameters_converted_through_converters(fields_names: &[Ident], fields_types: &[syn::Type], fields_converters: &[Option<FieldConverter>]) -> (Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>) {
+    fields_names.iter().zip(fields_types.iter()).zip(fields_converters.iter())
+        .map(|((field_name, field_type), converter)| match converter {
+            Some(converter) => (converter.from.to_token_stream(), converter.conversion(field_name, field_type, None)),
+            None => (field_type.to_token_stream(), proc_macro2::TokenStream::new()),
+        })
+        .unzip()
 }
 
+/// Statements performing the checks declared through ```#[validate(...)]``` on the fields, returning
+/// their variants of the error enum when they fail, along with said variants, their docs, and how
+/// they are displayed.<br>
+/// Failing checks run the statement ```failure``` gives for the field and variant of the error,
+/// like ```return Err(error);```, where the values of ```optional_fields``` are [Option]s which are
+/// only checked when they are [Some].
+#[allow(non_snake_case)]
+fn tokens_for__field_checks(name: &Ident, fields_names: &[Ident], fields_validations: &[Vec<FieldValidation>], error_enum_name: &Ident, optional_fields: &[Ident], failure: &dyn Fn(&Ident, proc_macro2::TokenStream) -> proc_macro2::TokenStream) -> (Vec<proc_macro2::TokenStream>, Vec<Ident>, Vec<proc_macro2::TokenStream>, Vec<ErrorEnumVariant>) {
+    let mut checks_variants = Vec::new();
+    let mut checks_variants_docs = Vec::new();
+    let mut checks_displayed = Vec::new();
+    let checks = fields_names.iter().zip(fields_validations.iter()).enumerate()
+        .flat_map(|(field_index, (field_name, validations))| validations.iter().map(move |validation| (field_index, field_name, validation)))
+        .map(|(field_index, field_name, validation)| {
+            let variant = validation.variant(field_name);
+            let failure = failure(field_name, quote!(#error_enum_name::#variant));
+            let check = match optional_fields.contains(field_name) {
+                true => {
+                    let condition = validation.condition(&field_name.to_token_stream());
+                    quote!(if let core::option::Option::Some(#field_name) = &#field_name { if !(#condition) { #failure } })
+                }
+                false => {
+                    let condition = validation.condition(&quote!(&#field_name));
+                    quote!(if !(#condition) { #failure })
+                }
+            };
+            if !checks_variants.contains(&variant) {
+                checks_variants_docs.push(docs::check_variant_docs(field_name, validation));
+                checks_displayed.push(ErrorEnumVariant::unit(&variant, validation.failure_message(&format!("{name}::{field_name}")), (field_name.to_string(), field_index)));
+                checks_variants.push(variant);
+            }
+            check
+        })
+        .collect::<Vec<_>>();
+    (checks, checks_variants, checks_variants_docs, checks_displayed)
+}
+
+/// A variant of an error enum as matched by the [std::fmt::Display] and [std::error::Error]
+/// implementations, along with the expressions writing it on the formatter and giving its source,
+/// and the name and index of the value it's about.
+struct ErrorEnumVariant {
+    variant: Ident,
+    pattern: proc_macro2::TokenStream,
+    display: proc_macro2::TokenStream,
+    source: proc_macro2::TokenStream,
+    /// Name and position among the constructor's parameters of the field this variant is about,
+    /// as returned by the generated ```field_name``` and ```field_index``` functions.
+    field: (String, usize),
+}
+
+impl ErrorEnumVariant {
+    /// Variant holding an error, which is displayed after the message and given as its source
+    /// when it implements [std::error::Error], see [tokens_for__error_enum_impls].
+    fn holding_error(variant: &Ident, message: String, field: (String, usize)) -> Self {
+        Self {
+            variant: variant.clone(),
+            pattern: quote!(#variant(ref error)),
+            display: quote!(write!(formatter, "{}: {}", #message, error)),
+            source: quote!((&ErrorSource(error)).error_source()),
+            field,
+        }
+    }
+
+    fn unit(variant: &Ident, message: String, field: (String, usize)) -> Self {
+        Self {
+            variant: variant.clone(),
+            pattern: variant.to_token_stream(),
+            display: quote!(formatter.write_str(#message)),
+            source: quote!(core::option::Option::None),
+            field,
+        }
+    }
+}
+
+/// Functions telling which field a variant of an error enum is about, these are ```field_name```,
+/// returning names like ```age```, and ```field_index```, returning their position among the
+/// constructor's parameters.
+#[allow(non_snake_case)]
+fn tokens_for__error_enum_fields(error_enum_name: &Ident, error_generics: &[Ident], variants: &[ErrorEnumVariant], vis: &syn::Visibility, cfg_attrs: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let variants_names = variants.iter().map(|variant| &variant.variant).collect::<Vec<_>>();
+    let fields_names = variants.iter().map(|variant| &variant.field.0);
+    let fields_indexes = variants.iter().map(|variant| variant.field.1);
+    let field_name_docs = docs::doc_attributes(&["Name of the field this error is about, like `age`, where errors of the validator are about `validation`, and errors on the length of rows are about `row`."]);
+    let field_index_docs = docs::doc_attributes(&["Position among the constructor's parameters of the field this error is about, where errors of the validator, and errors on the length of rows, are placed after the last field."]);
+    quote! {
+        #cfg_attrs
+        impl<#(#error_generics),*> #error_enum_name<#(#error_generics),*> {
+            #field_name_docs
+            #vis fn field_name(&self) -> &'static str {
+                match *self {
+                    #(#error_enum_name::#variants_names { .. } => #fields_names,)*
+                }
+            }
+
+            #field_index_docs
+            #vis fn field_index(&self) -> usize {
+                match *self {
+                    #(#error_enum_name::#variants_names { .. } => #fields_indexes,)*
+                }
+            }
+        }
+    }
+}
+
+/// Implementations of [std::fmt::Display] and [std::error::Error] for an error enum, as asked
+/// through ```error_enum_impls```, where the errors held by the variants are displayed after their
+/// messages, like ```CharacterInfo::age: number too large to fit in target type```, and returned
+/// by [std::error::Error::source] when they implement Error, which is told apart through autoref
+/// specialization, so errors like ```String``` are held too.<br>
+/// The error enum's generic parameters must implement Display and Error respectively, as only the
+/// latter's impl requires them to be errors.
+#[allow(non_snake_case)]
+fn tokens_for__error_enum_impls(error_enum_name: &Ident, error_generics: &[Ident], variants: &[ErrorEnumVariant], cfg_attrs: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let patterns = variants.iter().map(|variant| &variant.pattern).collect::<Vec<_>>();
+    let displays = variants.iter().map(|variant| &variant.display);
+    let sources = variants.iter().map(|variant| &variant.source);
+    quote! {
+        #cfg_attrs
+        impl<#(#error_generics: core::fmt::Display),*> core::fmt::Display for #error_enum_name<#(#error_generics),*> {
+            fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match *self {
+                    #(#error_enum_name::#patterns => #displays,)*
+                }
+            }
+        }
+
+        #cfg_attrs
+        impl<#(#error_generics: std::error::Error + 'static),*> std::error::Error for #error_enum_name<#(#error_generics),*> {
+            fn source(&self) -> core::option::Option<&(dyn std::error::Error + 'static)> {
+                struct ErrorSource<'error, HeldError>(&'error HeldError);
+                trait IsError<'error> {
+                    fn error_source(&self) -> core::option::Option<&'error (dyn std::error::Error + 'static)>;
+                }
+                impl<'error, HeldError: std::error::Error + 'static> IsError<'error> for ErrorSource<'error, HeldError> {
+                    fn error_source(&self) -> core::option::Option<&'error (dyn std::error::Error + 'static)> {
+                        core::option::Option::Some(self.0)
+                    }
+                }
+                trait IsNotError<'error> {
+                    fn error_source(&self) -> core::option::Option<&'error (dyn std::error::Error + 'static)> {
+                        core::option::Option::None
+                    }
+                }
+                impl<'error, HeldError> IsNotError<'error> for &ErrorSource<'error, HeldError> {}
+
+                match *self {
+                    #(#error_enum_name::#patterns => #sources,)*
+                }
+            }
+        }
+    }
+}
+
+/// Error enum shared by several TryFrom constructors, as declared through
+/// ```error_enum(define = Name)```, holding a variant for every field whose error can be named out
+/// of the field alone, see [SharedErrorEnum::variants], along with the checks of every field and
+/// the error of the defining constructor's validator, if any, which the validators of the rest
+/// must also return.
+#[allow(non_snake_case)]
+fn tokens_for__shared_error_enum(name: &Ident, error_enum_name: &Ident, shared_error_enum: &SharedErrorEnum, error_enum_metadata: &proc_macro2::TokenStream, implements_error: bool, constructor_info: &ConstructorInfo) -> proc_macro2::TokenStream {
+    let ConstructorInfo { validator, error_enum_vis: vis, cfg_attrs, .. } = constructor_info;
+    let SharedErrorEnum { fields_info, variants, .. } = shared_error_enum;
+    let fields_names = &fields_info.fields_names;
+    let ((fields_indexes, variants), variants_types) = fields_names.iter().zip(variants.iter()).enumerate()
+        .filter_map(|(field_index, (field_name, (variant, variant_type)))| variant_type.as_ref()
+            .map(|variant_type| (((field_name, field_index), variant), variant_type)))
+        .unzip::<_, _, (Vec<_>, Vec<_>), Vec<_>>();
+    let variants_docs = fields_indexes.iter().map(|(field_name, _)| docs::shared_error_variant_docs(field_name));
+    let (_, checks_variants, checks_variants_docs, checks_displayed) = tokens_for__field_checks(name, fields_names, &fields_info.fields_validations, error_enum_name, &[], &|_, _| proc_macro2::TokenStream::new());
+    let mut displayed_variants = fields_indexes.iter().zip(variants.iter())
+        .map(|((field_name, field_index), variant)| ErrorEnumVariant::holding_error(variant, format!("{name}::{field_name}"), (field_name.to_string(), *field_index)))
+        .collect::<Vec<_>>();
+    displayed_variants.extend(checks_displayed);
+    let validation_variant = validator.as_ref().map(|validator| {
+        displayed_variants.push(ErrorEnumVariant::holding_error(&format_ident!("ValidationError"), name.to_string(), ("validation".to_string(), fields_names.len())));
+        let Validator { error_type, .. } = validator;
+        let variant_docs = docs::validation_variant_docs(name, validator);
+        quote!(#variant_docs ValidationError(#error_type),)
+    });
+    let error_enum_impls = implements_error.then(|| tokens_for__error_enum_impls(error_enum_name, &[], &displayed_variants, cfg_attrs));
+    let error_enum_fields = tokens_for__error_enum_fields(error_enum_name, &[], &displayed_variants, vis, cfg_attrs);
+    let error_enum_docs = docs::doc_attributes(&[
+        format!("Error returned when a [`{name}`] couldn't be created through any of the constructors sharing it."),
+        "".to_string(),
+        "It holds a variant for every field declaring the type it's received as, or a converter that may fail, so every constructor returns this same type.".to_string(),
+    ]);
+    quote! {
+        #cfg_attrs
+        #error_enum_docs
+        #error_enum_metadata
+        #vis enum #error_enum_name {
+            #(#variants_docs #variants(#variants_types),)*
+            #(#checks_variants_docs #checks_variants,)*
+            #validation_variant
+        }
+
+        #error_enum_impls
+
+        #error_enum_fields
+    }
+}
+
+/// Struct returned by the TryFrom pattern when errors are collected through ```errors(collect)```,
+/// named like the error enum followed by an ```s```, as in ```CharacterInfoNewErrors```, which
+/// holds the first error found on each field that might fail, along with the error of the
+/// validator, if any.
+struct CollectedErrors {
+    struct_name: Ident,
+    name: Ident,
+    error_enum_name: Ident,
+    fields: Vec<Ident>,
+    has_validator: bool,
+    /// Local the errors are stored on while converting, named ```errors``` unless a field is.
+    local: Ident,
+}
+
+impl CollectedErrors {
+    fn new(struct_name: &Ident, error_enum_name: &Ident, fields_names: &[Ident], fields_validations: &[Vec<FieldValidation>], optional_fields: &[Ident], has_validator: bool, local: &Ident) -> Self {
+        let fields = fields_names.iter().zip(fields_validations.iter())
+            .filter(|(field_name, validations)| optional_fields.contains(field_name) || !validations.is_empty())
+            .map(|(field_name, _)| field_name.clone())
+            .collect::<Vec<_>>();
+        if has_validator && fields.iter().any(|field_name| field_name == "validation") {
+            panic!("Struct {struct_name} collects errors of a field named 'validation', which collides with the error of its validator, consider not collecting errors, or removing the validator")
+        }
+        Self { struct_name: struct_name.clone(), name: format_ident!("{error_enum_name}s"), error_enum_name: error_enum_name.clone(), fields, has_validator, local: local.clone() }
+    }
+
+    fn type_tokens(&self, error_types: &[Ident]) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        quote!(#name<#(#error_types),*>)
+    }
+
+    /// Declares the local every error found is stored on.
+    fn initialization(&self) -> proc_macro2::TokenStream {
+        let Self { name, fields, local, .. } = self;
+        let validation = self.has_validator.then(|| quote!(validation: core::option::Option::None,));
+        quote! {
+            #[allow(unused_mut)]
+            let mut #local = #name { #(#fields: core::option::Option::None,)* #validation };
+        }
+    }
+
+    /// Returns the errors found, if any, or unwraps the values of the fields that might have failed
+    /// otherwise.
+    fn checked_values(&self, optional_fields: &[Ident]) -> proc_macro2::TokenStream {
+        let Self { fields, local, .. } = self;
+        quote! {
+            let (#(#optional_fields,)*) = match (#(#optional_fields,)*) {
+                (#(core::option::Option::Some(#optional_fields),)*) if true #(&& #local.#fields.is_none())* => (#(#optional_fields,)*),
+                _ => return Err(#local),
+            };
+        }
+    }
+
+    fn struct_tokens(&self, error_types: &[Ident], error_enum_metadata: &proc_macro2::TokenStream, vis: &syn::Visibility, cfg_attrs: &proc_macro2::TokenStream, implements_error: bool) -> proc_macro2::TokenStream {
+        let Self { name, error_enum_name, fields, .. } = self;
+        let error_enum = quote!(#error_enum_name<#(#error_types),*>);
+        let (slots, labels) = fields.iter()
+            .map(|field_name| (field_name.clone(), field_name.to_string()))
+            .chain(self.has_validator.then(|| (format_ident!("validation"), "validation".to_string())))
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+        let slots_docs = labels.iter()
+            .map(|label| match self.has_validator && label == "validation" {
+                true => docs::doc_attributes(&["Error of the validator, which only runs once every field is valid."]),
+                false => docs::doc_attributes(&[format!("First error found on `{label}`, if any.")]),
+            })
+            .collect::<Vec<_>>();
+        let slots_count = slots.len();
+        let struct_docs = docs::doc_attributes(&[format!("Errors found when creating a [`{}`], holding the first error found on each value.", self.struct_name)]);
+        let derives = utils::standard_derives(error_enum_metadata);
+        let iter_docs = docs::doc_attributes(&["Iterates over the errors found, along with the name of the field they were found on."]);
+        let iter = derives.iter().any(|derive| derive.segments.last().is_some_and(|segment| segment.ident == "Debug")).then(|| quote! {
+            #cfg_attrs
+            impl<#(#error_types: core::fmt::Debug),*> #name<#(#error_types),*> {
+                #iter_docs
+                #vis fn iter(&self) -> impl Iterator<Item=(&'static str, &dyn core::fmt::Debug)> + '_ {
+                    let errors: [(&'static str, core::option::Option<&dyn core::fmt::Debug>); #slots_count] = [#((#labels, self.#slots.as_ref().map(|error| error as &dyn core::fmt::Debug))),*];
+                    errors.into_iter().filter_map(|(field_name, error)| error.map(|error| (field_name, error)))
+                }
+            }
+        });
+        let error_impls = implements_error.then(|| quote! {
+            #cfg_attrs
+            impl<#(#error_types: core::fmt::Display),*> core::fmt::Display for #name<#(#error_types),*> {
+                fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    let errors: [core::option::Option<&dyn core::fmt::Display>; #slots_count] = [#(self.#slots.as_ref().map(|error| error as &dyn core::fmt::Display)),*];
+                    for (index, error) in errors.into_iter().flatten().enumerate() {
+                        if index > 0 {
+                            formatter.write_str("; ")?;
+                        }
+                        write!(formatter, "{}", error)?;
+                    }
+                    Ok(())
+                }
+            }
+
+            #cfg_attrs
+            impl<#(#error_types: std::error::Error + 'static),*> std::error::Error for #name<#(#error_types),*> {}
+        });
+        quote! {
+            #cfg_attrs
+            #struct_docs
+            #[derive(#(#derives),*)]
+            #vis struct #name<#(#error_types),*> {
+                #(#slots_docs pub #slots: core::option::Option<#error_enum>,)*
+            }
 
-fn tokens_for__try_from__for_struct(name: Ident, fields_info: FieldsInfo, try_from_info: TryFromInfo, constructor_fn_name: Option<Ident>) -> TokenStream {
+            #iter
+
+            #error_impls
+        }
+    }
+}
+
+/// Struct returned by the TryFrom pattern when asked through ```errors(return_inputs)```, named
+/// like the error enum followed by ```WithInputs```, as in ```CharacterInfoNewErrorWithInputs```,
+/// which holds the error along with the values received, so they aren't lost.<br>
+/// It's generic over the error and the types of every value, so it doesn't depend on the
+/// struct's generics.
+struct ErrorWithInputs {
+    name: Ident,
+    fields: Vec<Ident>,
+}
+
+impl ErrorWithInputs {
+    fn new(struct_name: &Ident, name: Ident, fields_names: &[Ident], parameters_types: &[proc_macro2::TokenStream]) -> Self {
+        if let Some(field_name) = fields_names.iter().find(|field_name| *field_name == "error"
+            || fields_names.iter().any(|other_field| format_ident!("{other_field}_input") == **field_name)) {
+            panic!("Struct {struct_name} returns the values it receives through 'errors(return_inputs)', but field '{field_name}' collides with the names given to them, consider not returning them")
+        }
+        let elided_reference = fields_names.iter().zip(parameters_types.iter())
+            .find(|(_, parameter_type)| syn::parse2::<syn::Type>((*parameter_type).clone())
+                .is_ok_and(|parameter_type| utils::with_static_lifetimes(&parameter_type) != parameter_type));
+        if let Some((field_name, _)) = elided_reference {
+            panic!("Field '{field_name}' is received with an elided lifetime, so it can't be returned through 'errors(return_inputs)', consider receiving it with a ''static' lifetime, as in '&'static str', or as an owned value")
+        }
+        Self { name, fields: fields_names.to_vec() }
+    }
+
+    /// Generic parameters of the struct, these are ```Error``` and, for each field, one for its
+    /// converted value and another one for the value received, like ```Age``` and ```AgeInput```.
+    fn generic_parameters(&self) -> Vec<Ident> {
+        std::iter::once(format_ident!("Error"))
+            .chain(self.fields.iter().flat_map(|field_name| {
+                let generic_name = field_name.to_string().to_case(Case::Pascal);
+                [format_ident!("{generic_name}"), format_ident!("{generic_name}Input")]
+            }))
+            .collect()
+    }
+
+    fn type_tokens(&self, error_type: proc_macro2::TokenStream, fields_types: &[syn::Type], parameters_types: &[proc_macro2::TokenStream]) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        quote!(#name<#error_type, #(#fields_types, #parameters_types),*>)
+    }
+
+    fn struct_tokens(&self, built_through: &str, error_enum_metadata: &proc_macro2::TokenStream, vis: &syn::Visibility, cfg_attrs: &proc_macro2::TokenStream, implements_error: bool) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        let generic_parameters = self.generic_parameters();
+        let (values_generics, inputs_generics) = generic_parameters[1..].chunks(2)
+            .map(|generics| (&generics[0], &generics[1]))
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+        let fields = &self.fields;
+        let inputs = fields.iter().map(|field_name| format_ident!("{field_name}_input")).collect::<Vec<_>>();
+        let struct_docs = docs::doc_attributes(&[
+            format!("Error returned when a value couldn't be created through {built_through}, holding the values it was given, so they can be used again."),
+            "".to_string(),
+            "Values received before the one that failed are held already converted, while the ones after it are held as they were received, the value that failed is consumed by its conversion, and every value is held converted when the validator fails.".to_string(),
+        ]);
+        let error_docs = docs::doc_attributes(&["Why the value couldn't be created."]);
+        let values_docs = fields.iter()
+            .map(|field_name| docs::doc_attributes(&[format!("Value of `{field_name}`, if it was converted before the error was found.")]));
+        let inputs_docs = fields.iter()
+            .map(|field_name| docs::doc_attributes(&[format!("Value received for `{field_name}`, if the error was found before converting it.")]));
+        let derives = utils::standard_derives(error_enum_metadata);
+        let error_impls = implements_error.then(|| quote! {
+            #cfg_attrs
+            impl<Error: core::fmt::Display, #(#values_generics, #inputs_generics),*> core::fmt::Display for #name<#(#generic_parameters),*> {
+                fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    core::fmt::Display::fmt(&self.error, formatter)
+                }
+            }
+
+            #cfg_attrs
+            impl<Error: std::error::Error + 'static, #(#values_generics: core::fmt::Debug, #inputs_generics: core::fmt::Debug),*> std::error::Error for #name<#(#generic_parameters),*> {
+                fn source(&self) -> core::option::Option<&(dyn std::error::Error + 'static)> {
+                    self.error.source()
+                }
+            }
+        });
+        quote! {
+            #cfg_attrs
+            #struct_docs
+            #[derive(#(#derives),*)]
+            #vis struct #name<#(#generic_parameters),*> {
+                #error_docs
+                pub error: Error,
+                #(
+                    #values_docs
+                    pub #fields: core::option::Option<#values_generics>,
+                    #inputs_docs
+                    pub #inputs: core::option::Option<#inputs_generics>,
+                )*
+            }
+
+            #error_impls
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+fn tokens_for__try_from__for_struct(name: Ident, generics: Generics, fields_info: FieldsInfo, try_from_info: TryFromInfo, constructor_info: ConstructorInfo, constructor_fn_name: Option<Ident>) -> TokenStream {
+    check_validator_error_type(constructor_info.validator.as_ref(), &generics);
+    let ConstructorInfo { vis, error_enum_vis, fn_attrs, impl_attrs, cfg_attrs, .. } = &constructor_info;
+    let struct_literal = tokens_for__struct_literal(quote!(Self), &fields_info);
+    let built_through = match &constructor_fn_name {
+        Some(constructor_fn_name) => format!("[`{name}::{constructor_fn_name}`]"),
+        None => format!("[`TryFrom`] for [`{name}`]"),
+    };
+    let constructor_docs = docs::constructor_docs(
+        format!("Creates a new [`{name}`] converting each value into its field's type."), &fields_info, &constructor_info,
+        Some(match try_from_info.errors_mode {
+            ErrorsMode::FirstError => format!("Returns [`{}`] telling which value failed, and why.", try_from_info.error_enum_name),
+            ErrorsMode::Collect => format!("Returns [`{}s`] telling every value that failed, and why.", try_from_info.error_enum_name),
+            ErrorsMode::ReturnInputs => format!("Returns [`{}WithInputs`] telling which value failed, and why, along with the values it was given.", try_from_info.error_enum_name),
+        }));
+    let error_enum_docs = docs::doc_attributes(&[format!("Error returned when a [`{name}`] couldn't be created through {built_through}.")]);
     let FieldsInfo {
         fields_names,
         fields_types,
-        no_from_fields,
-        no_from_fields_initializers
+        fields_conversions,
+        fields_converters,
+        fields_input_types,
+        fields_validations,
+        default_bounds,
+        ..
     }
         = fields_info;
 
     let TryFromInfo {
         error_enum_metadata,
+        error_enum_impls,
+        errors_mode,
+        shared_error_enum,
         error_enum_name,
         error_types,
         try_from_types
     }
         = try_from_info;
 
+    let mut parameters_types = Vec::new();
+    let mut parameters_generics = Vec::new();
+    let mut parameters_bounds = Vec::<syn::WherePredicate>::new();
+    let mut conversions = Vec::new();
+    let mut fallible_error_types = Vec::new();
+    let mut conversion_variants_docs = Vec::new();
+    let mut concrete_variants = Vec::new();
+    let mut displayed_variants = Vec::new();
+    let mut optional_fields = Vec::new();
+    let mut fallible_fields_variants = Vec::new();
+    let field_of = |field_name: &Ident| (field_name.to_string(), fields_names.iter().position(|name| name == field_name).unwrap());
+    let with_inputs_name = format_ident!("{error_enum_name}WithInputs");
+    let errors_local = utils::ident_not_in("errors", &quote!(#(#fields_names)*));
+    // Values before the one at 'failed_index' are already converted, while the rest weren't yet, and
+    // without an index, every value was converted and taken back from the struct the validator
+    // rejected.
+    let returned_error = |failed_index: Option<usize>, error: proc_macro2::TokenStream| match errors_mode {
+        ErrorsMode::ReturnInputs => {
+            let values = fields_names.iter().enumerate()
+                .map(|(index, field_name)| {
+                    let input_slot = format_ident!("{field_name}_input");
+                    let (value, input) = match failed_index.map(|failed_index| index.cmp(&failed_index)) {
+                        Some(std::cmp::Ordering::Less) | None => (quote!(core::option::Option::Some(#field_name)), quote!(core::option::Option::None)),
+                        Some(std::cmp::Ordering::Greater) => (quote!(core::option::Option::None), quote!(core::option::Option::Some(#field_name))),
+                        Some(std::cmp::Ordering::Equal) => (quote!(core::option::Option::None), quote!(core::option::Option::None)),
+                    };
+                    quote!(#field_name: #value, #input_slot: #input)
+                });
+            quote!(#with_inputs_name { error: #error, #(#values,)* })
+        }
+        _ => error,
+    };
+    let fallible_conversion = |field_name: &Ident, conversion: proc_macro2::TokenStream, variant: &Ident| match errors_mode {
+        ErrorsMode::FirstError => quote!(let #field_name = #conversion.map_err(#error_enum_name::#variant)?;),
+        ErrorsMode::ReturnInputs => {
+            let returned_error = returned_error(Some(field_of(field_name).1), quote!(#error_enum_name::#variant(error)));
+            quote! {
+                let #field_name = match #conversion {
+                    core::result::Result::Ok(value) => value,
+                    core::result::Result::Err(error) => return Err(#returned_error),
+                };
+            }
+        }
+        ErrorsMode::Collect => quote! {
+            let #field_name = match #conversion {
+                core::result::Result::Ok(value) => core::option::Option::Some(value),
+                core::result::Result::Err(error) => {
+                    #errors_local.#field_name = core::option::Option::Some(#error_enum_name::#variant(error));
+                    core::option::Option::None
+                }
+            };
+        },
+    };
+    let fields_conversions = FieldConversion::for_try_from(&fields_conversions).into_iter().zip(fields_input_types.iter())
+        .map(|(conversion, input_type)| if input_type.is_some() { FieldConversion::TryFrom } else { conversion });
+    for ((((((field_name, field_type), conversion), converter), input_type), try_from_type), error_type) in fields_names.iter().zip(fields_types.iter())
+        .zip(fields_conversions).zip(fields_converters.iter()).zip(fields_input_types.iter()).zip(try_from_types.iter()).zip(error_types.iter()) {
+        if let Some(converter) = converter {
+            parameters_types.push(converter.from.to_token_stream());
+            if let Some(converter_error) = &converter.error {
+                let function = &converter.function;
+                conversions.push(fallible_conversion(field_name, quote!(#function(#field_name)), error_type));
+                optional_fields.push(field_name.clone());
+                fallible_fields_variants.push((field_name.clone(), error_type.clone(), Some(converter_error.to_token_stream())));
+                let variant_docs = docs::converter_error_variant_docs(field_name, converter);
+                concrete_variants.push(quote!(#variant_docs #error_type(#converter_error)));
+                displayed_variants.push(ErrorEnumVariant::holding_error(error_type, format!("{name}::{field_name}"), field_of(field_name)));
+            } else {
+                conversions.push(converter.conversion(field_name, field_type, None));
+            }
+            continue;
+        }
+        match conversion {
+            FieldConversion::TryFrom if input_type.as_ref().is_some_and(|input_type| !utils::type_uses_generics(field_type, &generics) && !utils::type_uses_generics(input_type, &generics)) => {
+                let input_type = input_type.as_ref().unwrap();
+                let static_input_type = utils::with_static_lifetimes(input_type);
+                let variant_docs = docs::conversion_error_variant_docs(field_name, &utils::pretty_tokens(field_type));
+                parameters_types.push(input_type.to_token_stream());
+                conversions.push(fallible_conversion(field_name, quote!(<#field_type>::try_from(#field_name)), error_type));
+                optional_fields.push(field_name.clone());
+                let conversion_error = quote!(<#field_type as core::convert::TryFrom<#static_input_type>>::Error);
+                concrete_variants.push(quote!(#variant_docs #error_type(#conversion_error)));
+                fallible_fields_variants.push((field_name.clone(), error_type.clone(), Some(conversion_error)));
+                displayed_variants.push(ErrorEnumVariant::holding_error(error_type, format!("{name}::{field_name}"), field_of(field_name)));
+            }
+            FieldConversion::TryFrom => {
+                let try_from_type = input_type.as_ref().map(ToTokens::to_token_stream).unwrap_or_else(|| try_from_type.to_token_stream());
+                parameters_types.push(try_from_type.clone());
+                parameters_generics.extend(input_type.is_none().then(|| try_from_type.clone()));
+                parameters_generics.push(error_type.to_token_stream());
+                parameters_bounds.push(parse_quote!(#field_type : core::convert::TryFrom< #try_from_type, Error=#error_type >));
+                conversions.push(fallible_conversion(field_name, quote!(<#field_type>::try_from(#field_name)), error_type));
+                optional_fields.push(field_name.clone());
+                fallible_error_types.push(error_type.clone());
+                fallible_fields_variants.push((field_name.clone(), error_type.clone(), None));
+                displayed_variants.push(ErrorEnumVariant::holding_error(error_type, format!("{name}::{field_name}"), field_of(field_name)));
+                conversion_variants_docs.push(docs::conversion_error_variant_docs(field_name, &utils::pretty_tokens(field_type)));
+            }
+            FieldConversion::Into => {
+                parameters_types.push(try_from_type.to_token_stream());
+                parameters_generics.push(try_from_type.to_token_stream());
+                parameters_bounds.push(parse_quote!(#try_from_type : core::convert::Into< #field_type >));
+                conversions.push(quote!(let #field_name: #field_type = #field_name.into();));
+            }
+            FieldConversion::Exact => parameters_types.push(field_type.to_token_stream()),
+        }
+    }
+    let error_types = fallible_error_types;
+
+    let (checks, checks_variants, checks_variants_docs, checks_displayed) = match errors_mode {
+        ErrorsMode::Collect => tokens_for__field_checks(&name, &fields_names, &fields_validations, &error_enum_name, &optional_fields,
+                                                        &|field_name, error| quote!(if #errors_local.#field_name.is_none() { #errors_local.#field_name = core::option::Option::Some(#error); })),
+        _ => tokens_for__field_checks(&name, &fields_names, &fields_validations, &error_enum_name, &[], &|_, error| {
+            let returned_error = returned_error(Some(fields_names.len()), error);
+            quote!(return Err(#returned_error);)
+        }),
+    };
+    displayed_variants.extend(checks_displayed);
+    if constructor_info.validator.is_some() {
+        displayed_variants.push(ErrorEnumVariant::holding_error(&format_ident!("ValidationError"), name.to_string(), ("validation".to_string(), fields_names.len())));
+    }
+    let validation_error_variant = constructor_info.validator.as_ref()
+        .map(|validator| {
+            let Validator { error_type, .. } = validator;
+            let variant_docs = docs::validation_variant_docs(&name, validator);
+            quote!(#variant_docs ValidationError(#error_type))
+        });
+    let (error_enum, error_enum_type) = match &shared_error_enum {
+        None => {
+            let error_enum_impls = error_enum_impls.then(|| tokens_for__error_enum_impls(&error_enum_name, &error_types, &displayed_variants, cfg_attrs));
+            let error_enum_fields = tokens_for__error_enum_fields(&error_enum_name, &error_types, &displayed_variants, error_enum_vis, cfg_attrs);
+            let error_enum = quote! {
+                #cfg_attrs
+                #error_enum_docs
+                #error_enum_metadata
+                #error_enum_vis enum #error_enum_name <#(#error_types),*>{
+                    #(#conversion_variants_docs #error_types (#error_types),)*
+                    #(#concrete_variants,)*
+                    #(#checks_variants_docs #checks_variants,)*
+                    #validation_error_variant
+                }
+
+                #error_enum_impls
+
+                #error_enum_fields
+            };
+            (error_enum, quote!(#error_enum_name<#(#error_types),*>))
+        }
+        Some(shared_error_enum) => {
+            let shared_variant_type = |variant: &Ident| shared_error_enum.variants.iter()
+                .find(|(shared_variant, _)| shared_variant == variant)
+                .and_then(|(_, variant_type)| variant_type.as_ref());
+            let unheld_field = fallible_fields_variants.iter()
+                .find(|(_, variant, _)| shared_variant_type(variant).is_none());
+            if let Some((field_name, _, _)) = unheld_field {
+                panic!("Field '{field_name}' might fail to be converted, but the shared error enum {error_enum_name} can't name its error, consider declaring the type it's received as through #[try_from(...)], or the error of its converter through #[convert(..., error = ...)], on the field itself, neither depending on the struct's generics")
+            }
+            // The enum holds the error of the conversion the field declares, so constructors
+            // receiving it as another type would fail with an error it can't hold.
+            let mismatched_field = fallible_fields_variants.iter()
+                .find(|(_, variant, variant_type)| variant_type.as_ref().map(ToString::to_string) != shared_variant_type(variant).map(ToString::to_string));
+            if let Some((field_name, variant, variant_type)) = mismatched_field {
+                let held_type = utils::pretty_tokens(shared_variant_type(variant).unwrap());
+                let failing_type = variant_type.as_ref().map(|variant_type| format!("'{}'", utils::pretty_tokens(variant_type)))
+                    .unwrap_or_else(|| "an error of a generic type".to_string());
+                panic!("Field '{field_name}' fails with {failing_type} on this constructor, but the shared error enum {error_enum_name} holds '{held_type}' on its {variant} variant, as declared on the field, consider receiving it as the field declares, or not sharing the error enum on this constructor")
+            }
+            let error_enum = shared_error_enum.defines
+                .then(|| tokens_for__shared_error_enum(&name, &error_enum_name, shared_error_enum, &error_enum_metadata, error_enum_impls, &constructor_info));
+            (error_enum.unwrap_or_default(), quote!(#error_enum_name))
+        }
+    };
+    let collected_errors = (errors_mode == ErrorsMode::Collect).then(|| CollectedErrors::new(&name, &error_enum_name, &fields_names, &fields_validations, &optional_fields, constructor_info.validator.is_some(), &errors_local));
+    let (validation_error_variant_path, error_type, checked_values, errors_struct) = match &collected_errors {
+        None if errors_mode == ErrorsMode::ReturnInputs => {
+            let returned_error = returned_error(None, quote!(#error_enum_name::ValidationError(error)));
+            let fields_members = &fields_info.fields_members;
+            let with_inputs = ErrorWithInputs::new(&name, with_inputs_name.clone(), &fields_names, &parameters_types);
+            (
+                quote!(|error| {
+                    let Self { #(#fields_members: #fields_names,)* .. } = value;
+                    #returned_error
+                }),
+                with_inputs.type_tokens(error_enum_type, &fields_types, &parameters_types),
+                proc_macro2::TokenStream::new(),
+                Some(with_inputs.struct_tokens(&built_through, &error_enum_metadata, error_enum_vis, cfg_attrs, error_enum_impls)),
+            )
+        }
+        None => (quote!(#error_enum_name::ValidationError), error_enum_type, proc_macro2::TokenStream::new(), None),
+        Some(collected_errors) => (
+            quote!(|error| { #errors_local.validation = core::option::Option::Some(#error_enum_name::ValidationError(error)); #errors_local }),
+            collected_errors.type_tokens(&error_types),
+            collected_errors.checked_values(&optional_fields),
+            Some(collected_errors.struct_tokens(&error_types, &error_enum_metadata, error_enum_vis, cfg_attrs, error_enum_impls)),
+        ),
+    };
+    let errors_initialization = collected_errors.as_ref().map(CollectedErrors::initialization);
+    let validated_struct = tokens_for__validated_struct(struct_literal, constructor_info.validator.as_ref(), Some(validation_error_variant_path));
+    let struct_generics = generics_with_default_bounds(&generics, &default_bounds);
+    let (_, ty_generics, _) = generics.split_for_impl();
 
     if constructor_fn_name.is_none() {
+        let mut try_from_generics = struct_generics.clone();
+        try_from_generics.params.extend(parameters_generics.iter().map(|parameter_generic| -> GenericParam { parse_quote!(#parameter_generic) }));
+        try_from_generics.make_where_clause().predicates.extend(parameters_bounds);
+        let (impl_generics, _, where_clause) = try_from_generics.split_for_impl();
+
         let res = quote! {
-            #error_enum_metadata
-            pub enum #error_enum_name <#(#error_types),*>{
-                #(#error_types (#error_types)),*
-            }
+            #error_enum
 
-            impl <#(#try_from_types , #error_types),*>
-                TryFrom<(#(#try_from_types),*)> for #name
-                where
-                    #(#fields_types : TryFrom< #try_from_types, Error=#error_types > ),*
-            {
+            #errors_struct
+
+            #cfg_attrs #impl_attrs
 
-                type Error = #error_enum_name<#(#error_types),*>;
+            impl #impl_generics core::convert::TryFrom<(#(#parameters_types),*)> for #name #ty_generics #where_clause {
 
-                fn try_from(value: (#(#try_from_types),*)) -> Result<Self, Self::Error> {
+                type Error = #error_type;
+
+                #fn_attrs
+
+                fn try_from(value: (#(#parameters_types),*)) -> Result<Self, Self::Error> {
 
                     let (#(#fields_names),*) = value;
-                    #(let #fields_names = <#fields_types>::try_from(#fields_names)
-                        .map_err(|error| #error_enum_name::#error_types(error)  )?; )*
-                    Ok(
-                        #name{
-                            #(#fields_names,)*
-                            #(#no_from_fields: #no_from_fields_initializers,)*
-                        }
-                    )
+                    #errors_initialization
+                    #(#conversions)*
+                    #(#checks)*
+                    #checked_values
+                    #validated_struct
                 }
             }
         };
@@ -316,26 +1333,27 @@ fn tokens_for__try_from__for_struct(name: Ident, fields_info: FieldsInfo, try_fr
         return res.into();
     }
 
+    let (impl_generics, _, where_clause) = struct_generics.split_for_impl();
     let constructor_fn_name = constructor_fn_name.unwrap();
     let res = quote! {
-        #error_enum_metadata
-        pub enum #error_enum_name <#(#error_types),*>{
-            #(#error_types (#error_types)),*
-        }
+        #error_enum
+
+        #errors_struct
+
+        #cfg_attrs #impl_attrs
 
-        impl #name {
-            pub fn #constructor_fn_name<#(#try_from_types , #error_types),*>(#(#fields_names: #try_from_types),*) -> Result<Self, #error_enum_name<#(#error_types),*>>
+        impl #impl_generics #name #ty_generics #where_clause {
+            #constructor_docs
+            #fn_attrs
+            #vis fn #constructor_fn_name<#(#parameters_generics),*>(#(#fields_names: #parameters_types),*) -> Result<Self, #error_type>
                 where
-                    #(#fields_types : TryFrom< #try_from_types, Error=#error_types > ),*
+                    #(#parameters_bounds),*
             {
-                    #(let #fields_names = <#fields_types>::try_from(#fields_names)
-                        .map_err(|error| #error_enum_name::#error_types(error)  )?; )*
-                    Ok(
-                        #name{
-                            #(#fields_names,)*
-                            #(#no_from_fields: #no_from_fields_initializers,)*
-                        }
-                    )
+                    #errors_initialization
+                    #(#conversions)*
+                    #(#checks)*
+                    #checked_values
+                    #validated_struct
             }
         }
 
@@ -345,21 +1363,208 @@ fn tokens_for__try_from__for_struct(name: Ident, fields_info: FieldsInfo, try_fr
     res.into()
 }
 
-fn tokens_for__from__for_struct(name: Ident, fields_info: FieldsInfo, constructor_fn_name: Option<Ident>) -> TokenStream {
+/// Constructor function taking a ```&str``` for each field, which are parsed into the fields' types
+/// through [core::str::FromStr], or into their converter's input type, along with a function
+/// taking all of them as a row, like ```&["Jorge", "23"]```.<br>
+/// The error enum holds each field's parsing error, where fields whose type depends on the struct's
+/// generics get a generic parameter for it, like on the TryFrom pattern.
+#[allow(non_snake_case)]
+fn tokens_for__from_str__for_struct(name: Ident, generics: Generics, fields_info: FieldsInfo, try_from_info: TryFromInfo, constructor_info: ConstructorInfo, constructor_fn_name: Ident, from_strs_fn_name: Ident) -> TokenStream {
+    let fields_info = fields_info.with_infallible_converters("FromStr");
+    check_validator_error_type(constructor_info.validator.as_ref(), &generics);
+    let ConstructorInfo { vis, error_enum_vis, fn_attrs, impl_attrs, cfg_attrs, .. } = &constructor_info;
+    let struct_literal = tokens_for__struct_literal(quote!(Self), &fields_info);
+    let TryFromInfo { error_enum_metadata, error_enum_impls, errors_mode, shared_error_enum, error_enum_name, error_types, .. } = try_from_info;
+    if shared_error_enum.is_some() {
+        panic!("Only the TryFrom pattern can share its error enum, consider removing 'error_enum' from the FromStr constructor")
+    }
+    if errors_mode != ErrorsMode::FirstError {
+        panic!("Only the TryFrom pattern can collect errors or return its inputs, consider removing 'errors' from the FromStr constructor")
+    }
+    let constructor_docs = docs::constructor_docs(
+        format!("Creates a new [`{name}`] parsing each value into its field's type through [`FromStr`](core::str::FromStr)."), &fields_info, &constructor_info,
+        Some(format!("Returns [`{error_enum_name}`] telling which value couldn't be parsed, and why.")));
+    let fields_count = fields_info.fields_names.len();
+    let from_strs_docs = docs::doc_attributes(&[
+        format!("Creates a new [`{name}`] out of a row holding a value for each field, in the order they are received by [`{name}::{constructor_fn_name}`], parsing them just like it does."),
+        "".to_string(), "# Errors".to_string(), "".to_string(),
+        format!("Returns [`{error_enum_name}`] telling which value couldn't be parsed, and why, or `RowLength` when the row doesn't hold {fields_count} values."),
+    ]);
+    let error_enum_docs = docs::doc_attributes(&[format!("Error returned when a [`{name}`] couldn't be parsed through [`{name}::{constructor_fn_name}`] or [`{name}::{from_strs_fn_name}`].")]);
     let FieldsInfo {
-        fields_names, fields_types,
-        no_from_fields, no_from_fields_initializers
+        fields_names, fields_types, fields_converters, fields_validations,
+        default_bounds,
+        ..
     } = fields_info;
 
+    let mut error_generics = Vec::new();
+    let mut error_bounds = Vec::<syn::WherePredicate>::new();
+    let mut parsing_variants = Vec::new();
+    let mut conversions = Vec::new();
+    let row_length_message = format!("{name}: expected a row of {{}} values, but found {{}}");
+    let mut displayed_variants = vec![ErrorEnumVariant {
+        variant: format_ident!("RowLength"),
+        pattern: quote!(RowLength { ref expected, ref found }),
+        display: quote!(write!(formatter, #row_length_message, expected, found)),
+        source: quote!(core::option::Option::None),
+        field: ("row".to_string(), fields_count),
+    }];
+    let field_of = |field_name: &Ident| (field_name.to_string(), fields_names.iter().position(|name| name == field_name).unwrap());
+    for (((field_name, field_type), converter), error_type) in fields_names.iter().zip(fields_types.iter()).zip(fields_converters.iter()).zip(error_types.iter()) {
+        let parsed_type = converter.as_ref().map(|converter| &converter.from).unwrap_or(field_type);
+        let variant_docs = docs::parsing_error_variant_docs(field_name, &utils::pretty_tokens(parsed_type));
+        match utils::type_uses_generics(parsed_type, &generics) {
+            true => {
+                error_generics.push(error_type.clone());
+                error_bounds.push(parse_quote!(#parsed_type : core::str::FromStr<Err=#error_type>));
+                parsing_variants.push(quote!(#variant_docs #error_type(#error_type)));
+            }
+            false => parsing_variants.push(quote!(#variant_docs #error_type(<#parsed_type as core::str::FromStr>::Err))),
+        }
+        conversions.push(quote!(let #field_name = #field_name.parse::<#parsed_type>().map_err(#error_enum_name::#error_type)?;));
+        displayed_variants.push(ErrorEnumVariant::holding_error(error_type, format!("{name}::{field_name}"), field_of(field_name)));
+        if let Some(converter) = converter {
+            conversions.push(converter.conversion(field_name, field_type, None));
+        }
+    }
+    let (checks, checks_variants, checks_variants_docs, checks_displayed) = tokens_for__field_checks(&name, &fields_names, &fields_validations, &error_enum_name, &[], &|_, error| quote!(return Err(#error);));
+    displayed_variants.extend(checks_displayed);
+    if constructor_info.validator.is_some() {
+        displayed_variants.push(ErrorEnumVariant::holding_error(&format_ident!("ValidationError"), name.to_string(), ("validation".to_string(), fields_names.len())));
+    }
+    let error_enum_impls = error_enum_impls.then(|| tokens_for__error_enum_impls(&error_enum_name, &error_generics, &displayed_variants, cfg_attrs));
+    let error_enum_fields = tokens_for__error_enum_fields(&error_enum_name, &error_generics, &displayed_variants, error_enum_vis, cfg_attrs);
+    let validation_error_variant = constructor_info.validator.as_ref()
+        .map(|validator| {
+            let Validator { error_type, .. } = validator;
+            let variant_docs = docs::validation_variant_docs(&name, validator);
+            quote!(#variant_docs ValidationError(#error_type))
+        });
+    let validated_struct = tokens_for__validated_struct(struct_literal, constructor_info.validator.as_ref(), Some(quote!(#error_enum_name::ValidationError)));
+    let row_length_docs = docs::doc_attributes(&["The row didn't hold a value for each field, holding how many values were expected and how many were found."]);
+    let expected_docs = docs::doc_attributes(&["Number of values the row should hold, one for each field."]);
+    let found_docs = docs::doc_attributes(&["Number of values the row held."]);
+
+    let struct_generics = generics_with_default_bounds(&generics, &default_bounds);
+    let (impl_generics, _, where_clause) = struct_generics.split_for_impl();
+    let (_, ty_generics, _) = generics.split_for_impl();
+
+    let res = quote! {
+        #cfg_attrs
+        #error_enum_docs
+        #error_enum_metadata
+        #error_enum_vis enum #error_enum_name <#(#error_generics),*>{
+            #(#parsing_variants,)*
+            #row_length_docs
+            RowLength {
+                #expected_docs
+                expected: usize,
+                #found_docs
+                found: usize,
+            },
+            #(#checks_variants_docs #checks_variants,)*
+            #validation_error_variant
+        }
+
+        #error_enum_impls
+
+        #error_enum_fields
+
+        #cfg_attrs #impl_attrs
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #constructor_docs
+            #fn_attrs
+            #vis fn #constructor_fn_name<#(#error_generics),*>(#(#fields_names: &str),*) -> Result<Self, #error_enum_name<#(#error_generics),*>>
+                where
+                    #(#error_bounds),*
+            {
+                #(#conversions)*
+                #(#checks)*
+                #validated_struct
+            }
+
+            #from_strs_docs
+            #fn_attrs
+            #vis fn #from_strs_fn_name<#(#error_generics),*>(values: &[&str]) -> Result<Self, #error_enum_name<#(#error_generics),*>>
+                where
+                    #(#error_bounds),*
+            {
+                match values {
+                    [#(#fields_names),*] => Self::#constructor_fn_name(#(#fields_names),*),
+                    _ => Err(#error_enum_name::RowLength { expected: #fields_count, found: values.len() }),
+                }
+            }
+        }
+    };
+    print_info(|| "Output", || format!("{res}"));
+    res.into()
+}
+
+#[allow(non_snake_case)]
+fn tokens_for__from__for_struct(name: Ident, generics: Generics, fields_info: FieldsInfo, constructor_info: ConstructorInfo, constructor_fn_name: Option<Ident>) -> TokenStream {
+    fields_info.check_fields_are_infallible("From");
+    let ConstructorInfo { vis, fn_attrs, impl_attrs, cfg_attrs, .. } = &constructor_info;
+    let constness = constructor_info.is_const.then(|| quote!(const));
+    let struct_literal = tokens_for__struct_literal(quote!(Self), &fields_info);
+    let constructor_docs = docs::constructor_docs(
+        format!("Creates a new [`{name}`] out of the given values."), &fields_info, &constructor_info,
+        constructor_info.validator.as_ref().map(|validator| format!("Returns the error of `{}` when it rejects the created [`{name}`].", utils::pretty_tokens(&validator.function))));
+    fields_info.check_fields_are_unchecked("From");
+    let FieldsInfo {
+        fields_names, fields_types, fields_converters,
+        default_bounds,
+        ..
+    } = fields_info;
+    let (parameters_types, conversions) = parameters_converted_through_converters(&fields_names, &fields_types, &fields_converters);
+
+    let struct_generics = generics_with_default_bounds(&generics, &default_bounds);
+    let (impl_generics, _, where_clause) = struct_generics.split_for_impl();
+    let (_, ty_generics, _) = generics.split_for_impl();
+
+    if let Some(validator) = &constructor_info.validator {
+        let error_type = &validator.error_type;
+        let validated_struct = tokens_for__validated_struct(struct_literal, Some(validator), None);
+        let res = match constructor_fn_name {
+            None => quote! {
+                #cfg_attrs #impl_attrs
+                impl #impl_generics core::convert::TryFrom<(#(#parameters_types),*)> for #name #ty_generics #where_clause {
+                    type Error = #error_type;
+
+                    #fn_attrs
+
+                    fn try_from(value: (#(#parameters_types),* )) -> Result<Self, Self::Error> {
+                        let (#(#fields_names),*) = value;
+                        #(#conversions)*
+                        #validated_struct
+                    }
+                }
+            },
+            Some(constructor_fn_name) => quote! {
+                #cfg_attrs #impl_attrs
+                impl #impl_generics #name #ty_generics #where_clause {
+                    #constructor_docs
+                    #fn_attrs
+                    #vis fn #constructor_fn_name( #(#fields_names: #parameters_types),*  ) -> Result<Self, #error_type> {
+                        #(#conversions)*
+                        #validated_struct
+                    }
+                }
+            },
+        };
+        print_info(|| "Derive input res", || format!("{res}"));
+        return res.into();
+    }
+
     if constructor_fn_name.is_none() {
         let res = quote! {
-            impl core::convert::From<(#(#fields_types),*)> for #name {
-                fn from(value: (#(#fields_types),* )) -> Self {
+            #cfg_attrs #impl_attrs
+            impl #impl_generics core::convert::From<(#(#parameters_types),*)> for #name #ty_generics #where_clause {
+                #fn_attrs
+                fn from(value: (#(#parameters_types),* )) -> Self {
                     let (#(#fields_names),*) = value;
-                    Self {
-                        #(#fields_names,)*
-                        #(#no_from_fields : #no_from_fields_initializers),*
-                    }
+                    #(#conversions)*
+                    #struct_literal
                 }
             }
         };
@@ -369,12 +1574,13 @@ fn tokens_for__from__for_struct(name: Ident, fields_info: FieldsInfo, constructo
 
     let constructor_fn_name = constructor_fn_name.unwrap();
     let res = quote! {
-            impl #name{
-                pub fn #constructor_fn_name( #(#fields_names: #fields_types),*  ) -> Self{
-                    Self {
-                        #(#fields_names,)*
-                        #(#no_from_fields : #no_from_fields_initializers),*
-                    }
+            #cfg_attrs #impl_attrs
+            impl #impl_generics #name #ty_generics #where_clause {
+                #constructor_docs
+                #fn_attrs
+                #vis #constness fn #constructor_fn_name( #(#fields_names: #parameters_types),*  ) -> Self{
+                    #(#conversions)*
+                    #struct_literal
                 }
             }
         };
@@ -382,7 +1588,347 @@ fn tokens_for__from__for_struct(name: Ident, fields_info: FieldsInfo, constructo
     res.into()
 }
 
-fn tokens_for__from__for_enum(name: Ident, enum_data: DataEnum) -> TokenStream {
+/// Constructor function taking ```impl Into<FieldType>``` parameters, when some of the fields are
+/// marked with ```#[into]```, only those take an ```impl Into<FieldType>```, while the rest take
+/// their exact type, this keeps inference working for parameters such as numeric literals.
+#[allow(non_snake_case)]
+fn tokens_for__into__for_struct(name: Ident, generics: Generics, fields_info: FieldsInfo, constructor_info: ConstructorInfo, constructor_fn_name: Ident) -> TokenStream {
+    fields_info.check_fields_are_infallible("Into");
+    let ConstructorInfo { vis, fn_attrs, impl_attrs, cfg_attrs, .. } = &constructor_info;
+    let struct_literal = tokens_for__struct_literal(quote!(Self), &fields_info);
+    let constructor_docs = docs::constructor_docs(
+        format!("Creates a new [`{name}`] out of the given values, converting them into their field's type through [`Into`]."), &fields_info, &constructor_info,
+        constructor_info.validator.as_ref().map(|validator| format!("Returns the error of `{}` when it rejects the created [`{name}`].", utils::pretty_tokens(&validator.function))));
+    fields_info.check_fields_are_unchecked("Into");
+    let FieldsInfo {
+        fields_names, fields_types, fields_conversions, fields_converters,
+        default_bounds,
+        ..
+    } = fields_info;
+
+    let struct_generics = generics_with_default_bounds(&generics, &default_bounds);
+    let (impl_generics, _, where_clause) = struct_generics.split_for_impl();
+    let (_, ty_generics, _) = generics.split_for_impl();
+
+    let every_field_is_into = !fields_conversions.contains(&Some(FieldConversion::Into));
+    let (parameters_types, conversions) = fields_names.iter().zip(fields_types.iter()).zip(fields_conversions.iter()).zip(fields_converters.iter())
+        .map(|(((field_name, field_type), conversion), converter)| {
+            if let Some(converter) = converter {
+                (converter.from.to_token_stream(), converter.conversion(field_name, field_type, None))
+            } else if conversion.eq(&Some(FieldConversion::Into)) || (every_field_is_into && conversion.ne(&Some(FieldConversion::Exact))) {
+                (quote!(impl core::convert::Into<#field_type>), quote!(let #field_name = #field_name.into();))
+            } else {
+                (field_type.to_token_stream(), proc_macro2::TokenStream::new())
+            }
+        })
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+
+    let (return_type, body) = match &constructor_info.validator {
+        None => (quote!(Self), struct_literal),
+        Some(validator) => {
+            let error_type = &validator.error_type;
+            (quote!(Result<Self, #error_type>), tokens_for__validated_struct(struct_literal, Some(validator), None))
+        }
+    };
+    let res = quote! {
+            #cfg_attrs #impl_attrs
+            impl #impl_generics #name #ty_generics #where_clause {
+                #constructor_docs
+                #fn_attrs
+                #vis fn #constructor_fn_name( #(#fields_names: #parameters_types),*  ) -> #return_type {
+                    #(#conversions)*
+                    #body
+                }
+            }
+        };
+    print_info(|| "Derive input res", || format!("{res}"));
+    res.into()
+}
+
+/// Type a builder's setter takes for a field, along with the expression turning the parameter,
+/// named like the field, into the field's value.
+fn setter_type_and_value(field_name: &Ident, field_type: &syn::Type, conversion: &Option<FieldConversion>, converter: &Option<FieldConverter>) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    if let Some(FieldConverter { function, from, .. }) = converter {
+        return (from.to_token_stream(), quote!(#function(#field_name)));
+    }
+    match conversion {
+        Some(FieldConversion::Into) => (quote!(impl core::convert::Into<#field_type>), quote!(#field_name.into())),
+        _ => (field_type.to_token_stream(), field_name.to_token_stream()),
+    }
+}
+
+/// Builder with a setter for each field and a ```build``` function applying the defaults, where every
+/// field that isn't defaulted must be set, or ```build``` returns an error telling it's missing.
+#[allow(non_snake_case)]
+fn tokens_for__builder__for_struct(name: Ident, generics: Generics, fields_info: FieldsInfo, builder_info: BuilderInfo, constructor_info: ConstructorInfo, constructor_fn_name: Ident) -> TokenStream {
+    fields_info.check_fields_are_infallible("Builder");
+    check_validator_error_type(constructor_info.validator.as_ref(), &generics);
+    let ConstructorInfo { vis, error_enum_vis, fn_attrs, impl_attrs, cfg_attrs, .. } = &constructor_info;
+    let struct_literal = tokens_for__struct_literal(name.to_token_stream(), &fields_info);
+    let builder_docs = docs::builder_constructor_docs(&name, &builder_info.builder_name, &fields_info, &constructor_info);
+    let error_enum_docs = docs::doc_attributes(&[format!("Error returned when [`{}::build`] couldn't create a [`{name}`].", builder_info.builder_name)]);
+    let missing_field_variants_docs = fields_info.fields_names.iter()
+        .map(|field_name| docs::doc_attributes(&[format!("`{field_name}` wasn't set before calling `build`.")]))
+        .collect::<Vec<_>>();
+    let build_docs = docs::doc_attributes(&[
+        format!("Creates the [`{name}`] out of the values set, initializing the rest of fields with their defaults."),
+        "".to_string(), "# Errors".to_string(), "".to_string(),
+        format!("Returns [`{}`] when a value that has no default wasn't set.", builder_info.error_enum_name),
+    ]);
+    fields_info.check_fields_are_unchecked("Builder");
+    let FieldsInfo {
+        fields_names, fields_types, fields_conversions, fields_converters, fields_docs,
+        default_bounds,
+        ..
+    } = fields_info;
+    let setters_docs = fields_names.iter().zip(fields_docs.iter())
+        .map(|(field_name, field_doc)| docs::setter_docs(field_name, field_doc))
+        .collect::<Vec<_>>();
+    let BuilderInfo {
+        builder_name,
+        error_enum_metadata,
+        error_enum_name,
+        missing_field_variants,
+    } = builder_info;
+
+    let struct_generics = generics_with_default_bounds(&generics, &default_bounds);
+    let (impl_generics, _, where_clause) = struct_generics.split_for_impl();
+    let (builder_generics, ty_generics, builder_where_clause) = generics.split_for_impl();
+
+    let (setters_types, setters_values) = fields_names.iter().zip(fields_types.iter()).zip(fields_conversions.iter()).zip(fields_converters.iter())
+        .map(|(((field_name, field_type), conversion), converter)| setter_type_and_value(field_name, field_type, conversion, converter))
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+    let validation_error_variant = constructor_info.validator.as_ref()
+        .map(|validator| {
+            let Validator { error_type, .. } = validator;
+            let variant_docs = docs::validation_variant_docs(&name, validator);
+            quote!(#variant_docs ValidationError(#error_type))
+        });
+    let validated_struct = tokens_for__validated_struct(struct_literal, constructor_info.validator.as_ref(), Some(quote!(#error_enum_name::ValidationError)));
+    let builder_struct_docs = docs::doc_attributes(&[format!("Builder for [`{name}`], created through [`{name}::{constructor_fn_name}`].")]);
+
+    let res = quote! {
+        #cfg_attrs
+        #error_enum_docs
+        #error_enum_metadata
+        #error_enum_vis enum #error_enum_name {
+            #(#missing_field_variants_docs #missing_field_variants,)*
+            #validation_error_variant
+        }
+
+        #cfg_attrs
+
+        #builder_struct_docs
+        #vis struct #builder_name #builder_generics #builder_where_clause {
+            #(#fields_names: core::option::Option<#fields_types>,)*
+            _built: core::marker::PhantomData<fn() -> #name #ty_generics>,
+        }
+
+        #cfg_attrs #impl_attrs
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #builder_docs
+            #fn_attrs
+            #vis fn #constructor_fn_name() -> #builder_name #ty_generics {
+                #builder_name {
+                    #(#fields_names: core::option::Option::None,)*
+                    _built: core::marker::PhantomData,
+                }
+            }
+        }
+
+        #cfg_attrs #impl_attrs
+
+        impl #impl_generics #builder_name #ty_generics #where_clause {
+            #(
+                #setters_docs
+                #vis fn #fields_names(mut self, #fields_names: #setters_types) -> Self {
+                    self.#fields_names = core::option::Option::Some(#setters_values);
+                    self
+                }
+            )*
+
+            #build_docs
+            #vis fn build(self) -> Result<#name #ty_generics, #error_enum_name> {
+                #(let #fields_names = self.#fields_names.ok_or(#error_enum_name::#missing_field_variants)?;)*
+                #validated_struct
+            }
+        }
+    };
+    print_info(|| "Output", || format!("{res}"));
+    res.into()
+}
+
+/// Builder where whether each field of the constructor has been set is tracked as a type parameter,
+/// being ```()``` while unset and ```(FieldType,)``` once set, so ```build``` only exists once every
+/// one of them is set, while the fields with defaults can be optionally set.
+#[allow(non_snake_case)]
+fn tokens_for__typestate_builder__for_struct(name: Ident, generics: Generics, fields_info: FieldsInfo, builder_info: BuilderInfo, constructor_info: ConstructorInfo, constructor_fn_name: Ident) -> TokenStream {
+    fields_info.check_fields_are_infallible("TypestateBuilder");
+    let ConstructorInfo { vis, fn_attrs, impl_attrs, cfg_attrs, .. } = &constructor_info;
+    let optional_fields = fields_info.no_from_fields_types.iter()
+        .map(|no_from_type| !utils::is_phantom_data(no_from_type))
+        .collect::<Vec<_>>();
+    let mut built_fields_info = fields_info.clone();
+    built_fields_info.no_from_fields_initializers = fields_info.no_from_fields_names.iter()
+        .zip(fields_info.no_from_fields_initializers.iter()).zip(optional_fields.iter())
+        .map(|((no_from_name, initializer), is_optional)| match is_optional {
+            true => quote!(self.#no_from_name.unwrap_or_else(|| #initializer)),
+            false => initializer.clone(),
+        })
+        .collect();
+    let struct_literal = tokens_for__struct_literal(name.to_token_stream(), &built_fields_info);
+    let builder_docs = docs::builder_constructor_docs(&name, &builder_info.builder_name, &fields_info, &constructor_info);
+    let mut build_docs = vec![format!("Creates the [`{name}`] out of the values set, initializing the rest of fields with their defaults.")];
+    if let Some(validator) = &constructor_info.validator {
+        build_docs.extend(["".to_string(), "# Errors".to_string(), "".to_string(),
+            format!("Returns the error of `{}` when it rejects the created [`{name}`].", utils::pretty_tokens(&validator.function))]);
+    }
+    let build_docs = docs::doc_attributes(&build_docs);
+    fields_info.check_fields_are_unchecked("TypestateBuilder");
+    let taken_idents = {
+        let builder_name = &builder_info.builder_name;
+        let FieldsInfo { fields_types, no_from_fields_types, .. } = &fields_info;
+        quote!(#name #builder_name #generics #(#fields_types)* #(#no_from_fields_types)*)
+    };
+    let FieldsInfo {
+        fields_names, fields_types, fields_conversions, fields_converters, fields_docs,
+        no_from_fields_names, no_from_fields_types, no_from_fields_docs,
+        default_bounds,
+        ..
+    } = fields_info;
+    let (optional_names, (optional_types, optional_setters_docs)) = no_from_fields_names.into_iter().zip(no_from_fields_types.into_iter().zip(no_from_fields_docs)).zip(optional_fields)
+        .filter(|(_, is_optional)| *is_optional)
+        .map(|((optional_name, (optional_type, optional_doc)), _)| {
+            let setter_docs = docs::setter_docs(&optional_name, &optional_doc);
+            (optional_name, (optional_type, setter_docs))
+        })
+        .unzip::<_, _, Vec<_>, (Vec<_>, Vec<_>)>();
+    let builder_name = builder_info.builder_name;
+
+    let (_, ty_generics, builder_where_clause) = generics.split_for_impl();
+    let (built_type, built_struct) = match &constructor_info.validator {
+        None => (quote!(#name #ty_generics), struct_literal),
+        Some(validator) => {
+            let error_type = &validator.error_type;
+            (quote!(Result<#name #ty_generics, #error_type>), tokens_for__validated_struct(struct_literal, Some(validator), None))
+        }
+    };
+    let struct_arguments = generics.params.iter()
+        .map(|param| match param {
+            GenericParam::Type(type_param) => type_param.ident.to_token_stream(),
+            GenericParam::Lifetime(lifetime) => lifetime.lifetime.to_token_stream(),
+            GenericParam::Const(const_param) => const_param.ident.to_token_stream(),
+        })
+        .collect::<Vec<_>>();
+    let states = fields_names.iter()
+        .map(|field_name| utils::ident_not_in(&format!("{}State", field_name.to_string().to_case(Case::Pascal)), &taken_idents))
+        .collect::<Vec<_>>();
+    let unset_states = states.iter().map(|_| quote!(())).collect::<Vec<_>>();
+    let set_states = fields_types.iter().map(|field_type| quote!((#field_type,))).collect::<Vec<_>>();
+
+    let mut builder_generics = generics.clone();
+    builder_generics.params.extend(states.iter().map(|state| -> GenericParam { parse_quote!(#state) }));
+    let (builder_impl_generics, builder_ty_generics, _) = builder_generics.split_for_impl();
+    let builder_ty_generics = builder_ty_generics.to_token_stream();
+
+    let setters = fields_names.iter().zip(fields_types.iter()).zip(fields_conversions.iter()).zip(fields_converters.iter()).zip(fields_docs.iter()).enumerate()
+        .map(|(index, ((((field_name, field_type), conversion), converter), field_doc))| {
+            let setter_docs = docs::setter_docs(field_name, field_doc);
+            let (setter_type, setter_value) = setter_type_and_value(field_name, field_type, conversion, converter);
+            let mut setter_generics = generics.clone();
+            setter_generics.params.extend(states.iter().enumerate()
+                .filter(|(state_index, _)| *state_index != index)
+                .map(|(_, state)| -> GenericParam { parse_quote!(#state) }));
+            let (setter_impl_generics, _, _) = setter_generics.split_for_impl();
+            let states_before = states.iter().enumerate()
+                .map(|(state_index, state)| if state_index == index { quote!(()) } else { state.to_token_stream() });
+            let states_after = states.iter().zip(set_states.iter()).enumerate()
+                .map(|(state_index, (state, set_state))| if state_index == index { set_state.clone() } else { state.to_token_stream() });
+            let other_fields = fields_names.iter().filter(|other_field| other_field != &field_name);
+            quote! {
+                #cfg_attrs #impl_attrs
+                impl #setter_impl_generics #builder_name <#(#struct_arguments,)* #(#states_before),*> #builder_where_clause {
+                    #setter_docs
+                    #vis fn #field_name(self, #field_name: #setter_type) -> #builder_name <#(#struct_arguments,)* #(#states_after),*> {
+                        #builder_name {
+                            #field_name: (#setter_value,),
+                            #(#other_fields: self.#other_fields,)*
+                            #(#optional_names: self.#optional_names,)*
+                            _built: core::marker::PhantomData,
+                        }
+                    }
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let struct_generics = generics_with_default_bounds(&generics, &default_bounds);
+    let (impl_generics, _, where_clause) = struct_generics.split_for_impl();
+    let mut optional_setters_generics = generics.clone();
+    optional_setters_generics.params.extend(states.iter().map(|state| -> GenericParam { parse_quote!(#state) }));
+    let (optional_setters_impl_generics, _, _) = optional_setters_generics.split_for_impl();
+
+    let builder_struct_docs = docs::doc_attributes(&[
+        format!("Builder for [`{name}`], created through [`{name}::{constructor_fn_name}`]."),
+        "".to_string(),
+        "Each of its type parameters tells whether a field has been set, being `()` until it is.".to_string(),
+    ]);
+
+    let res = quote! {
+        #cfg_attrs
+        #builder_struct_docs
+        #vis struct #builder_name #builder_impl_generics #builder_where_clause {
+            #(#fields_names: #states,)*
+            #(#optional_names: core::option::Option<#optional_types>,)*
+            _built: core::marker::PhantomData<fn() -> #name #ty_generics>,
+        }
+
+        #cfg_attrs #impl_attrs
+
+        impl #impl_generics #name #ty_generics #builder_where_clause {
+            #builder_docs
+            #fn_attrs
+            #vis fn #constructor_fn_name() -> #builder_name <#(#struct_arguments,)* #(#unset_states),*> {
+                #builder_name {
+                    #(#fields_names: (),)*
+                    #(#optional_names: core::option::Option::None,)*
+                    _built: core::marker::PhantomData,
+                }
+            }
+        }
+
+        #(#setters)*
+
+        #cfg_attrs #impl_attrs
+
+        impl #optional_setters_impl_generics #builder_name #builder_ty_generics #builder_where_clause {
+            #(
+                #optional_setters_docs
+                #vis fn #optional_names(mut self, #optional_names: #optional_types) -> Self {
+                    self.#optional_names = core::option::Option::Some(#optional_names);
+                    self
+                }
+            )*
+        }
+
+        #cfg_attrs #impl_attrs
+
+        impl #impl_generics #builder_name <#(#struct_arguments,)* #(#set_states),*> #where_clause {
+            #build_docs
+            #vis fn build(self) -> #built_type {
+                #(let #fields_names = self.#fields_names.0;)*
+                #built_struct
+            }
+        }
+    };
+    print_info(|| "Output", || format!("{res}"));
+    res.into()
+}
+
+#[allow(non_snake_case)]
+fn tokens_for__from__for_enum(name: Ident, generics: Generics, enum_data: DataEnum) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let impls = enum_data.variants.iter()
         .filter(|variant| utils::find_attribute(&variant.attrs, "no_from").is_none())
         .map(|variant| {
@@ -390,7 +1936,7 @@ fn tokens_for__from__for_enum(name: Ident, enum_data: DataEnum) -> TokenStream {
             let (fieldnames, types) = variant.fields.iter()
                 .map(|fields| (fields.ident.as_ref().map(ToString::to_string), fields.ty.to_token_stream()))
                 .unzip::<_, _, Vec<_>, Vec<_>>();
-            let is_named = fieldnames.get(0).is_some_and(|name| name.is_some());
+            let is_named = fieldnames.first().is_some_and(|name| name.is_some());
 
             let fieldnames = fieldnames
                 .into_iter()
@@ -414,7 +1960,7 @@ fn tokens_for__from__for_enum(name: Ident, enum_data: DataEnum) -> TokenStream {
                 };
 
             let res = quote! {
-                impl core::convert::From<(#(#types),*)> for #name {
+                impl #impl_generics core::convert::From<(#(#types),*)> for #name #ty_generics #where_clause {
                     fn from(value: (#(#types),* )) -> Self {
                         Self:: #variant_name { #(#fieldnames : value #indexes),* }
                     }
@@ -424,9 +1970,7 @@ fn tokens_for__from__for_enum(name: Ident, enum_data: DataEnum) -> TokenStream {
             res
         })
         .collect::<Vec<_>>();
-    let res = impls.into_iter().reduce(|token1, token2| {
-        quote! { #token1 #token2}
-    }).unwrap();
+    let res = quote! { #(#impls)* };
     print_info(|| "Output", || format!("{res}"));
     TokenStream::from(res)
 }
@@ -1,106 +1,602 @@
-use syn::{Attribute, DataStruct, DeriveInput, parse_str, Type};
-use quote::{quote, ToTokens};
+use syn::{Attribute, Data, DataStruct, DeriveInput, Field, Generics, Member, parse_quote, Path, Type, Visibility};
+use syn::parse::{ParseStream, Parser};
+use quote::{format_ident, quote, ToTokens};
 use proc_macro2::Ident;
-use proc_macro::TokenStream;
 use std::collections::HashMap;
 use convert_case::{Case, Casing};
-use crate::utils::idents_and_groups_from;
-use crate::utils::{ExpectElseOption, ExpectElseResult, extract_token_stream_of_attribute, find_attribute, print_info};
+use crate::utils::{fields_of_option, idents_and_groups_from};
+use crate::utils::{doc_of, pretty_tokens, ExpectElseOption, ExpectElseResult, extract_token_stream_of_attribute, find_attribute, is_phantom_data, print_info, tokens_reference_ident, type_uses_generics, with_static_lifetimes};
 
+#[derive(Clone)]
 pub(crate) struct FieldsInfo {
+    /// Names given to the values of these fields inside the constructor, which are the fields'
+    /// names, or ```field_{index}``` for positional fields.
     pub(crate) fields_names: Vec<Ident>,
+    /// How these fields are accessed in the struct's literal, this is, ```name``` or ```0```.
+    pub(crate) fields_members: Vec<Member>,
     pub(crate) fields_types: Vec<Type>,
-    pub(crate) no_from_fields: Vec<Ident>,
+    /// Documentation of these fields, as given through their ```///``` comments.
+    pub(crate) fields_docs: Vec<String>,
+    /// Conversion asked for each field through attributes like ```#[into]```, if any.
+    pub(crate) fields_conversions: Vec<Option<FieldConversion>>,
+    /// Types the TryFrom pattern receives these fields as, when declared through
+    /// ```fields(age: u16)``` or ```#[try_from(u16)]```, which avoids a generic parameter for them.
+    pub(crate) fields_input_types: Vec<Option<Type>>,
+    /// Functions building each field out of another type, as given through ```#[convert(...)]```
+    /// or the ```convert``` option, if any.
+    pub(crate) fields_converters: Vec<Option<FieldConverter>>,
+    /// Checks declared for each field through ```#[validate(...)]```, which only the TryFrom and
+    /// FromStr constructors perform.
+    pub(crate) fields_validations: Vec<Vec<FieldValidation>>,
+    /// Names given through ```#[error_variant(InvalidAge)]``` to the variant of the error enum
+    /// holding the error of each field, if any.
+    pub(crate) fields_error_variants: Vec<Option<Ident>>,
+    pub(crate) no_from_fields: Vec<Member>,
+    /// Names given to the values of the fields that aren't received as parameters, which follow
+    /// the same rules as [FieldsInfo::fields_names].
+    pub(crate) no_from_fields_names: Vec<Ident>,
+    pub(crate) no_from_fields_types: Vec<Type>,
+    pub(crate) no_from_fields_docs: Vec<String>,
+    /// Expressions initializing the fields that aren't received as parameters, these are evaluated
+    /// in the order the fields are declared, so they can use the constructor's parameters and the
+    /// fields declared before them.
     pub(crate) no_from_fields_initializers: Vec<proc_macro2::TokenStream>,
+    /// Types of the fields initialized through [Default::default] that depend on the struct's
+    /// generics, these need a ```FieldType: Default``` bound on the generated impls.
+    pub(crate) default_bounds: Vec<Type>,
+    /// Fields that aren't given any initializer, so they are initialized through
+    /// [Default::default].
+    pub(crate) default_initialized_fields: Vec<Member>,
 }
 
 impl FieldsInfo {
-    pub(crate) fn new_from_derive_data_struct(data: &DataStruct) -> FieldsInfo {
-        let (fields_names, fields_types) = data.fields.iter()
-            .filter(|field| find_attribute(&field.attrs, "no_from").is_none())
-            .map(|field| (field.ident.clone().unwrap(), field.ty.clone()))
-            .unzip::<_, _, Vec<_>, Vec<_>>();
+    pub(crate) fn new_from_derive_data_struct(data: &DataStruct, generics: &Generics) -> FieldsInfo {
+        let ((fields_names, fields_members), (fields_types, (fields_conversions, fields_validations))) = data.fields.iter()
+            .enumerate()
+            .filter(|(_, field)| find_attribute(&field.attrs, "no_from").is_none() && !is_phantom_data(&field.ty))
+            .map(|(index, field)| ((Self::field_name(index, field), Self::field_member(index, field)),
+                                   (field.ty.clone(), (FieldConversion::from_attributes(&field.attrs), FieldValidation::from_attributes(&field.attrs)))))
+            .unzip::<_, _, (Vec<_>, Vec<_>), (Vec<_>, (Vec<_>, Vec<_>))>();
         print_info(|| "Fields", || format!("{fields_names:#?}"));
 
+        let mut default_bounds = Vec::new();
+        let mut default_initialized_fields = Vec::new();
         let (no_from_fields, no_from_fields_initializers) = data.fields.iter()
-            .map(|field| (field, find_attribute(&field.attrs, "no_from")))
-            .filter(|(_, attribute_opt)| attribute_opt.is_some())
-            .map(|(field, attribute_opt)| (field, attribute_opt.unwrap()))
-            .map(|(field, attribute)| {
-                let initializer = extract_token_stream_of_attribute(attribute).map(Into::into).unwrap_or_else(|| quote!(core::default::Default::default()));
+            .enumerate()
+            .filter(|(_, field)| find_attribute(&field.attrs, "no_from").is_some() || is_phantom_data(&field.ty))
+            .map(|(index, field)| {
+                let initializer = find_attribute(&field.attrs, "no_from")
+                    .and_then(extract_token_stream_of_attribute)
+                    .map(Into::into)
+                    .unwrap_or_else(|| Self::implicit_initializer(index, field, generics, &mut default_bounds, &mut default_initialized_fields));
                 print_info(|| "Ident", || format!("{:#?}", field.ident.as_ref()));
-                (field.ident.clone().unwrap(), initializer)
+                (Self::field_member(index, field), initializer)
             })
             .unzip::<_, _, Vec<_>, Vec<_>>();
         print_info(|| "No from fields", || format!("{no_from_fields:#?}"));
+        let (no_from_fields_names, no_from_fields_types) = Self::names_and_types_of(data, &no_from_fields);
+        Self::check_initializers_order(&no_from_fields_names, &no_from_fields_initializers);
 
-        let info = FieldsInfo { fields_names, fields_types, no_from_fields, no_from_fields_initializers };
-        info
+        FieldsInfo {
+            fields_input_types: fields_members.iter().map(|member| Self::input_type_of(Self::find_field(data, member).1)).collect(),
+            fields_converters: Self::converters_of(data, &fields_members, Vec::new()),
+            fields_error_variants: Self::error_variants_of(data, &fields_members),
+            fields_docs: Self::docs_of(data, &fields_members),
+            no_from_fields_docs: Self::docs_of(data, &no_from_fields),
+            fields_names,
+            fields_members,
+            fields_types,
+            fields_conversions,
+            fields_validations,
+            no_from_fields,
+            no_from_fields_names,
+            no_from_fields_types,
+            no_from_fields_initializers,
+            default_bounds,
+            default_initialized_fields,
+        }
     }
 
-    pub(crate) fn new_from_macro_attribute_info(data: &DataStruct, attr_contents: &mut HashMap<String, proc_macro2::TokenStream>) -> FieldsInfo {
-        let mut idents_and_groups = attr_contents;
-        print_info(|| "Info", || format!("{idents_and_groups:#?}"));
+    pub(crate) fn new_from_macro_attribute_info(data: &DataStruct, generics: &Generics, attr_contents: &mut HashMap<String, proc_macro2::TokenStream>) -> FieldsInfo {
+        print_info(|| "Info", || format!("{attr_contents:#?}"));
 
-        let (mut no_from_fields, mut no_from_initializers) = idents_and_groups.remove("defaults")
+        let (mut no_from_fields, mut no_from_initializers) = attr_contents.remove("defaults")
             .map(|token| idents_and_groups_from(token.to_token_stream())
                 .expect_else(|_| "Could not resolve groups and descriptions inside attribute 'defaults'")
                 .into_iter()
-                .map(|(ident, group)| (ident, group))
                 .unzip::<_, _, Vec<_>, Vec<_>>())
-            .unwrap_or_else(|| Default::default());
-
-        let fields_in_use = idents_and_groups.remove("fields")
-            .map(|fields_token| fields_token.to_string()
-                .split(",")
-                .map(|field_name| parse_str::<Ident>(field_name.trim()).unwrap())
-                .collect::<Vec<_>>()
-            )
+            .unwrap_or_default();
+        no_from_fields.iter()
+            .for_each(|no_from_field| { Self::find_field(data, no_from_field); });
+
+        let option_converters = attr_contents.remove("convert")
+            .map(|option| FieldConverter::from_option(data, option))
+            .unwrap_or_default();
+
+        let (fields_in_use, declared_input_types) = attr_contents.remove("fields")
+            .map(|fields_token| fields_of_option(fields_token).into_iter().unzip::<_, _, Vec<_>, Vec<_>>())
             .unwrap_or_else(|| data.fields.iter()
-                .filter(|field| !no_from_fields.contains(field.ident.as_ref().unwrap()))
-                .map(|field| field.ident.clone().unwrap()).collect());
+                .enumerate()
+                .map(|(index, field)| (Self::field_member(index, field), field))
+                .filter(|(member, field)| !no_from_fields.contains(member) && !is_phantom_data(&field.ty)
+                    && find_attribute(&field.attrs, "no_from").is_none())
+                .map(|(member, _)| (member, None)).unzip());
+        let fields_in_use_input_types = fields_in_use.iter().zip(declared_input_types)
+            .map(|(member, declared_input_type)| {
+                let has_converter = option_converters.iter().any(|(converted_member, _)| converted_member == member)
+                    || FieldConverter::from_attributes(&Self::find_field(data, member).1.attrs).is_some();
+                if declared_input_type.is_some() && has_converter {
+                    panic!("Field '{}' is given a converter, so it can't declare the type it's received as", member.to_token_stream());
+                }
+                declared_input_type.or_else(|| Self::input_type_of(Self::find_field(data, member).1))
+            })
+            .collect();
 
-        let fields_in_use_types = fields_in_use.iter()
+        let (fields_in_use_names, (fields_in_use_types, (fields_in_use_conversions, fields_in_use_validations))) = fields_in_use.iter()
             .map(|constructor_field| {
-                data.fields.iter().filter(|field| field.ident.as_ref().is_some_and(|ident| ident.eq(constructor_field))).next().unwrap()
-                    .ty.clone()
+                let (index, field) = Self::find_field(data, constructor_field);
+                if is_phantom_data(&field.ty) {
+                    panic!("Field '{}' is a PhantomData, these are always initialized as PhantomData, consider removing it from the 'fields' attribute", constructor_field.to_token_stream());
+                }
+                (Self::field_name(index, field), (field.ty.clone(), (FieldConversion::from_attributes(&field.attrs), FieldValidation::from_attributes(&field.attrs))))
             })
-            .collect::<Vec<_>>();
+            .unzip::<_, _, Vec<_>, (Vec<_>, (Vec<_>, Vec<_>))>();
 
+        let mut default_bounds = Vec::new();
+        let mut default_initialized_fields = Vec::new();
         let (unreached_field, unreached_initializers) =
             data.fields.iter()
-                .map(|field| field.ident.clone().unwrap())
-                .filter(|name| !fields_in_use.contains(name) && !no_from_fields.contains(name))
-                .map(|name| (name, quote! {core::default::Default::default()}))
+                .enumerate()
+                .map(|(index, field)| (Self::field_member(index, field), field))
+                .filter(|(member, _)| !fields_in_use.contains(member) && !no_from_fields.contains(member))
+                .map(|(member, field)| {
+                    let index = Self::find_field(data, &member).0;
+                    let initializer = find_attribute(&field.attrs, "no_from")
+                        .and_then(extract_token_stream_of_attribute)
+                        .map(Into::into)
+                        .unwrap_or_else(|| Self::implicit_initializer(index, field, generics, &mut default_bounds, &mut default_initialized_fields));
+                    (member, initializer)
+                })
                 .unzip::<_, _, Vec<_>, Vec<_>>();
 
-        no_from_fields.extend(unreached_field.into_iter());
-        no_from_initializers.extend(unreached_initializers.into_iter());
+        no_from_fields.extend(unreached_field);
+        no_from_initializers.extend(unreached_initializers);
+        let (no_from_fields, no_from_initializers) = no_from_fields.into_iter().zip(no_from_initializers)
+            .map(|(member, initializer)| (Self::find_field(data, &member).0, (member, initializer)))
+            .collect::<std::collections::BTreeMap<_, _>>()
+            .into_values()
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+        let (no_from_fields_names, no_from_fields_types) = Self::names_and_types_of(data, &no_from_fields);
+        Self::check_initializers_order(&no_from_fields_names, &no_from_initializers);
 
         FieldsInfo {
-            fields_names: fields_in_use,
+            fields_input_types: fields_in_use_input_types,
+            fields_converters: Self::converters_of(data, &fields_in_use, option_converters),
+            fields_error_variants: Self::error_variants_of(data, &fields_in_use),
+            fields_docs: Self::docs_of(data, &fields_in_use),
+            no_from_fields_docs: Self::docs_of(data, &no_from_fields),
+            fields_names: fields_in_use_names,
+            fields_members: fields_in_use,
             fields_types: fields_in_use_types,
-            no_from_fields: no_from_fields,
+            fields_conversions: fields_in_use_conversions,
+            fields_validations: fields_in_use_validations,
+            no_from_fields,
+            no_from_fields_names,
+            no_from_fields_types,
             no_from_fields_initializers: no_from_initializers,
+            default_bounds,
+            default_initialized_fields,
+        }
+    }
+
+    /// Every field a constructor might receive, this is, every field but
+    /// [PhantomData](core::marker::PhantomData) ones, even those marked with ```#[no_from]```, as
+    /// constructors can still receive them through ```fields(...)```.
+    pub(crate) fn new_for_every_field(data: &DataStruct, generics: &Generics) -> FieldsInfo {
+        let every_field = data.fields.iter()
+            .enumerate()
+            .filter(|(_, field)| !is_phantom_data(&field.ty))
+            .map(|(index, field)| Self::field_member(index, field));
+        let mut attr_contents = HashMap::from([("fields".to_string(), quote!(#(#every_field),*))]);
+        Self::new_from_macro_attribute_info(data, generics, &mut attr_contents)
+    }
+
+    /// Name given to a field's value inside constructors, positional fields are named
+    /// ```field_{index}```, for example, ```field_0```.
+    pub(crate) fn field_name(index: usize, field: &Field) -> Ident {
+        field.ident.clone().unwrap_or_else(|| format_ident!("field_{index}"))
+    }
+
+    /// Member used to access a field, for example, ```name``` for named fields and ```0``` for
+    /// positional ones.
+    pub(crate) fn field_member(index: usize, field: &Field) -> Member {
+        field.ident.clone().map(Member::Named).unwrap_or_else(|| Member::Unnamed(index.into()))
+    }
+
+    fn names_and_types_of(data: &DataStruct, members: &[Member]) -> (Vec<Ident>, Vec<Type>) {
+        members.iter()
+            .map(|member| {
+                let (index, field) = Self::find_field(data, member);
+                (Self::field_name(index, field), field.ty.clone())
+            })
+            .unzip()
+    }
+
+    /// Panics if the initializer of a field uses a field declared after it, as initializers are
+    /// evaluated in the order the fields are declared, meaning the later doesn't have a value yet.
+    fn check_initializers_order(no_from_fields_names: &[Ident], initializers: &[proc_macro2::TokenStream]) {
+        initializers.iter().enumerate()
+            .for_each(|(index, initializer)| {
+                let later_field = no_from_fields_names[index + 1..].iter()
+                    .find(|later_field| tokens_reference_ident(initializer.clone(), later_field));
+                if let Some(later_field) = later_field {
+                    let field = &no_from_fields_names[index];
+                    panic!("The default value of field '{field}' uses field '{later_field}', but defaults are initialized in the order fields are declared, so '{later_field}' has no value yet, consider declaring '{later_field}' before '{field}'");
+                }
+            });
+    }
+
+    fn docs_of(data: &DataStruct, members: &[Member]) -> Vec<String> {
+        members.iter()
+            .map(|member| doc_of(&Self::find_field(data, member).1.attrs))
+            .collect()
+    }
+
+    /// Names of the error enum's variants given to fields through ```#[error_variant(InvalidAge)]```.
+    fn error_variants_of(data: &DataStruct, members: &[Member]) -> Vec<Option<Ident>> {
+        members.iter()
+            .map(|member| find_attribute(&Self::find_field(data, member).1.attrs, "error_variant")
+                .map(|attribute| attribute.parse_args::<Ident>()
+                    .expect_else(|_| format!("Could not parse #[error_variant] of field '{}', it should be given the name of the variant, like '#[error_variant(InvalidAge)]'", member.to_token_stream()))))
+            .collect()
+    }
+
+    /// Type a field is received as, as declared through ```#[try_from(InputType)]```.
+    fn input_type_of(field: &Field) -> Option<Type> {
+        find_attribute(&field.attrs, "try_from")
+            .filter(|attribute| !attribute.tokens.is_empty())
+            .map(|attribute| attribute.parse_args::<Type>()
+                .expect_else(|_| "Could not parse the type given to #[try_from], it should look like '#[try_from(u16)]'"))
+    }
+
+    /// Removes the converters that may fail declared through ```#[convert(...)]``` on the fields,
+    /// as the FromStr pattern parses these fields as they are, while panicking if one of them is
+    /// given through the ```convert``` option.
+    pub(crate) fn with_infallible_converters(mut self, pattern: &str) -> Self {
+        for (field_name, converter) in self.fields_names.iter().zip(self.fields_converters.iter_mut()) {
+            let Some(fallible_converter) = converter.as_ref().filter(|converter| converter.error.is_some()) else {
+                continue;
+            };
+            if !fallible_converter.declared_on_field {
+                panic!("Field '{field_name}' is given a converter that may fail, which the {pattern} pattern doesn't support, consider using the TryFrom pattern")
+            }
+            *converter = None;
+        }
+        self
+    }
+
+    /// Panics if any field might fail to be converted, this is, if it's marked with
+    /// ```#[try_from]``` or given a converter that may fail, as only the TryFrom pattern has an
+    /// error to return their errors through.
+    pub(crate) fn check_fields_are_infallible(&self, pattern: &str) {
+        let try_from_field = self.fields_names.iter().zip(self.fields_conversions.iter()).zip(self.fields_converters.iter())
+            .find(|((_, conversion), converter)| converter.is_none() && **conversion == Some(FieldConversion::TryFrom));
+        if let Some(((field_name, _), _)) = try_from_field {
+            panic!("Field '{field_name}' is marked with #[try_from], whose conversion may fail, which the {pattern} pattern doesn't support, consider using the TryFrom pattern or not receiving this field")
+        }
+        let fallible_field = self.fields_names.iter().zip(self.fields_converters.iter())
+            .find(|(_, converter)| converter.as_ref().is_some_and(|converter| converter.error.is_some()));
+        if let Some((field_name, _)) = fallible_field {
+            panic!("Field '{field_name}' is given a converter that may fail, which the {pattern} pattern doesn't support, consider using the TryFrom pattern or not receiving this field")
+        }
+    }
+
+    /// Panics if any field declares checks through ```#[validate(...)]```, as only the TryFrom and
+    /// FromStr patterns have an error to return their failures through.
+    pub(crate) fn check_fields_are_unchecked(&self, pattern: &str) {
+        let checked_field = self.fields_names.iter().zip(self.fields_validations.iter())
+            .find(|(_, validations)| !validations.is_empty());
+        if let Some((field_name, _)) = checked_field {
+            panic!("Field '{field_name}' declares checks through #[validate], which the {pattern} pattern doesn't perform, consider using the TryFrom pattern or not receiving this field")
+        }
+    }
+
+    /// Converters of the given fields, where those given through the ```convert``` option replace
+    /// the ones declared through ```#[convert(...)]```.
+    fn converters_of(data: &DataStruct, members: &[Member], mut option_converters: Vec<(Member, FieldConverter)>) -> Vec<Option<FieldConverter>> {
+        if let Some((member, _)) = option_converters.iter().find(|(member, _)| !members.contains(member)) {
+            panic!("Field '{}' is given a converter, but it isn't received by the constructor, consider adding it to 'fields'", member.to_token_stream());
+        }
+        members.iter()
+            .map(|member| {
+                let field = Self::find_field(data, member).1;
+                let converter = option_converters.iter().position(|(converted_member, _)| converted_member == member)
+                    .map(|position| option_converters.remove(position).1)
+                    .or_else(|| FieldConverter::from_attributes(&field.attrs));
+                if converter.is_some() && FieldConversion::from_attributes(&field.attrs).is_some() {
+                    panic!("Field '{}' is given a converter, so it can't be marked with #[into], #[try_from] or #[exact]", member.to_token_stream());
+                }
+                converter
+            })
+            .collect()
+    }
+
+    fn find_field<'data>(data: &'data DataStruct, member: &Member) -> (usize, &'data Field) {
+        data.fields.iter()
+            .enumerate()
+            .find(|(index, field)| Self::field_member(*index, field).eq(member))
+            .expect_else(|| format!("There is no field named '{}'", member.to_token_stream()))
+    }
+
+    /// Initializer for a field whose value hasn't been specified, this is, [PhantomData] for
+    /// PhantomData fields and [Default::default] for everything else, where the later might need
+    /// a ```FieldType: Default``` bound when the field's type depends on the struct's generics.
+    ///
+    /// [PhantomData]: core::marker::PhantomData
+    fn implicit_initializer(index: usize, field: &Field, generics: &Generics, default_bounds: &mut Vec<Type>, default_initialized_fields: &mut Vec<Member>) -> proc_macro2::TokenStream {
+        if is_phantom_data(&field.ty) {
+            return quote!(core::marker::PhantomData);
         }
+        if type_uses_generics(&field.ty, generics) {
+            default_bounds.push(field.ty.clone());
+        }
+        default_initialized_fields.push(Self::field_member(index, field));
+        quote!(core::default::Default::default())
+    }
+}
+
+/// Conversion a constructor applies to a field's parameter, as asked by the field's attributes.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum FieldConversion {
+    /// ```#[into]```: The parameter is any type implementing ```Into<FieldType>```.
+    Into,
+    /// ```#[try_from]```: On TryFrom constructors, the parameter is any type the field's type
+    /// implements ```TryFrom``` for, getting its own variant on the error enum.
+    TryFrom,
+    /// ```#[exact]```: The parameter is the field's type itself.
+    Exact,
+}
+
+impl FieldConversion {
+    const ATTRIBUTES: [(&'static str, FieldConversion); 3] = [("into", FieldConversion::Into), ("try_from", FieldConversion::TryFrom), ("exact", FieldConversion::Exact)];
+
+    pub(crate) fn from_attributes(attrs: &[Attribute]) -> Option<FieldConversion> {
+        let conversions = Self::ATTRIBUTES.iter()
+            .filter(|(attribute_name, _)| find_attribute(attrs, attribute_name).is_some())
+            .collect::<Vec<_>>();
+        if conversions.len() > 1 {
+            let attribute_names = conversions.iter().map(|(attribute_name, _)| format!("#[{attribute_name}]")).collect::<Vec<_>>().join(", ");
+            panic!("A field can only be marked with one of #[into], #[try_from] or #[exact], but found {attribute_names}")
+        }
+        conversions.first().map(|(_, conversion)| *conversion)
+    }
+
+    /// Conversions TryFrom constructors apply to each parameter, where fields not marked with
+    /// ```#[into]``` nor ```#[exact]``` are converted through ```TryFrom```.
+    pub(crate) fn for_try_from(fields_conversions: &[Option<FieldConversion>]) -> Vec<FieldConversion> {
+        fields_conversions.iter()
+            .map(|conversion| conversion.unwrap_or(FieldConversion::TryFrom))
+            .collect()
     }
 }
 
+/// Function building a field out of a value of another type, as declared through
+/// ```#[convert(with = path::to::function, from = InputType)]```, where if the function returns a
+/// [Result], its error type is given through ```error = ErrorType```.
+#[derive(Clone, Debug)]
+pub(crate) struct FieldConverter {
+    pub(crate) function: Path,
+    pub(crate) from: Type,
+    pub(crate) error: Option<Type>,
+    /// Whether it's declared through ```#[convert(...)]``` on the field, rather than through the
+    /// ```convert``` option, where converters that may fail only apply to TryFrom constructors.
+    pub(crate) declared_on_field: bool,
+}
+
+impl FieldConverter {
+    pub(crate) fn from_attributes(attrs: &[Attribute]) -> Option<FieldConverter> {
+        find_attribute(attrs, "convert")
+            .map(|attribute| attribute.parse_args_with(Self::parse)
+                .expect_else(|_| "Could not parse field's #[convert] attribute, it should look like '#[convert(with = path::to::function, from = InputType)]', along with 'error = ErrorType' if the function returns a Result"))
+            .map(|converter| FieldConverter { declared_on_field: true, ..converter })
+    }
+
+    /// Converters given through the ```convert``` option, like
+    /// ```convert(timeout(with = Duration::from_millis, from = u64))```.
+    pub(crate) fn from_option(data: &DataStruct, option: proc_macro2::TokenStream) -> Vec<(Member, FieldConverter)> {
+        idents_and_groups_from(option)
+            .expect_else(|_| "Could not resolve groups and descriptions inside attribute 'convert'")
+            .into_iter()
+            .map(|(member, converter)| {
+                FieldsInfo::find_field(data, &member);
+                let converter = Self::parse.parse2(converter)
+                    .expect_else(|_| format!("Could not parse the converter of field '{}', it should look like '{}(with = path::to::function, from = InputType)'",
+                                             member.to_token_stream(), member.to_token_stream()));
+                (member, converter)
+            })
+            .collect()
+    }
+
+    fn parse(input: ParseStream) -> syn::Result<FieldConverter> {
+        let mut function = None;
+        let mut from = None;
+        let mut error = None;
+        while !input.is_empty() {
+            let name = input.parse::<Ident>()?;
+            input.parse::<syn::Token![=]>()?;
+            match name.to_string().as_str() {
+                "with" => function = Some(input.parse()?),
+                "from" => from = Some(input.parse()?),
+                "error" => error = Some(input.parse()?),
+                _ => return Err(syn::Error::new(name.span(), format!("Unknown converter option '{name}', expected 'with', 'from' or 'error'"))),
+            }
+            if !input.is_empty() {
+                input.parse::<syn::Token![,]>()?;
+            }
+        }
+        let function = function.ok_or_else(|| input.error("Missing the converting function, given like 'with = path::to::function'"))?;
+        let from = from.ok_or_else(|| input.error("Missing the type to convert from, given like 'from = InputType'"))?;
+        Ok(FieldConverter { function, from, error, declared_on_field: false })
+    }
+
+    /// Statement turning the parameter under the field's name into the field's value, where errors
+    /// are wrapped by ```error_variant```.<br>
+    /// The value is declared as the field's type, so a function returning a [Result] that wasn't
+    /// given an ```error = ErrorType``` gets its type mismatch reported on the function itself.
+    pub(crate) fn conversion(&self, field_name: &Ident, field_type: &Type, error_variant: Option<proc_macro2::TokenStream>) -> proc_macro2::TokenStream {
+        let function = &self.function;
+        let map_err = error_variant.map(|variant| quote!(.map_err(#variant)?));
+        let converted = quote::quote_spanned!(syn::spanned::Spanned::span(function)=> #function(#field_name) #map_err);
+        quote!(let #field_name: #field_type = #converted;)
+    }
+}
+
+/// Check a field's value must pass once converted, as declared through
+/// ```#[validate(range(0..150), non_empty, len(1..=32), with = path::to::predicate)]```.
+#[derive(Clone, Debug)]
+pub(crate) enum FieldValidation {
+    /// ```range(..)```: The value must be contained in the given range.
+    Range(proc_macro2::TokenStream),
+    /// ```non_empty```: The value's ```is_empty()``` must return false.
+    NonEmpty,
+    /// ```len(..)```: The value's ```len()``` must be contained in the given range.
+    Length(proc_macro2::TokenStream),
+    /// ```with = path::to::predicate```: The predicate, taking a reference to the value, must
+    /// return true.
+    With(Path),
+}
+
+impl FieldValidation {
+    pub(crate) fn from_attributes(attrs: &[Attribute]) -> Vec<FieldValidation> {
+        attrs.iter()
+            .filter(|attribute| attribute.path.is_ident("validate"))
+            .flat_map(|attribute| attribute.parse_args_with(Self::parse_list)
+                .expect_else(|_| "Could not parse field's #[validate] attribute, the checks available are 'range(..)', 'non_empty', 'len(..)' and 'with = path::to::predicate'"))
+            .collect()
+    }
+
+    fn parse_list(input: ParseStream) -> syn::Result<Vec<FieldValidation>> {
+        let mut validations = Vec::new();
+        while !input.is_empty() {
+            let name = input.parse::<Ident>()?;
+            let validation = match name.to_string().as_str() {
+                "range" => {
+                    let content;
+                    syn::parenthesized!(content in input);
+                    FieldValidation::Range(content.parse()?)
+                }
+                "non_empty" => FieldValidation::NonEmpty,
+                "len" => {
+                    let content;
+                    syn::parenthesized!(content in input);
+                    FieldValidation::Length(content.parse()?)
+                }
+                "with" => {
+                    input.parse::<syn::Token![=]>()?;
+                    FieldValidation::With(input.parse()?)
+                }
+                _ => return Err(syn::Error::new(name.span(), format!("Unknown check '{name}'"))),
+            };
+            validations.push(validation);
+            if !input.is_empty() {
+                input.parse::<syn::Token![,]>()?;
+            }
+        }
+        Ok(validations)
+    }
+
+    /// Message telling this check failed for the given field, like
+    /// ```CharacterInfo::age isn't in the range 0..150```.
+    pub(crate) fn failure_message(&self, field: &str) -> String {
+        match self {
+            FieldValidation::Range(range) => format!("{field} isn't in the range {}", pretty_tokens(range)),
+            FieldValidation::NonEmpty => format!("{field} is empty"),
+            FieldValidation::Length(range) => format!("the length of {field} isn't in the range {}", pretty_tokens(range)),
+            FieldValidation::With(predicate) => format!("{field} was rejected by {}", pretty_tokens(predicate)),
+        }
+    }
+
+    /// Variant of the error enum telling this check failed for a field, like ```AgeOutOfRange```.
+    pub(crate) fn variant(&self, field_name: &Ident) -> Ident {
+        let field_name = field_name.to_string().to_case(Case::Pascal);
+        match self {
+            FieldValidation::Range(_) => format_ident!("{field_name}OutOfRange"),
+            FieldValidation::NonEmpty => format_ident!("{field_name}Empty"),
+            FieldValidation::Length(_) => format_ident!("{field_name}InvalidLength"),
+            FieldValidation::With(_) => format_ident!("{field_name}Rejected"),
+        }
+    }
+
+    /// Expression that is true when the value passes this check, where ```value_reference``` is
+    /// an expression giving a reference to the value, like ```&age```.
+    pub(crate) fn condition(&self, value_reference: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            FieldValidation::Range(range) => quote!(core::ops::RangeBounds::contains(&(#range), #value_reference)),
+            FieldValidation::NonEmpty => quote!(!(#value_reference).is_empty()),
+            FieldValidation::Length(range) => quote!(core::ops::RangeBounds::contains(&(#range), &(#value_reference).len())),
+            FieldValidation::With(predicate) => quote!(#predicate(#value_reference)),
+        }
+    }
+}
+
+/// How the TryFrom pattern returns the errors it finds.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ErrorsMode {
+    /// Returns the first error found, which is the default.
+    FirstError,
+    /// Converts and checks every field even after one fails, returning every error found, as asked
+    /// through ```errors(collect)```.
+    Collect,
+    /// Returns the first error found along with the values received, as asked through
+    /// ```errors(return_inputs)```.
+    ReturnInputs,
+}
+
 pub(crate) struct TryFromInfo {
     pub(crate) error_enum_metadata: proc_macro2::TokenStream,
+    /// Whether the error enum implements [std::fmt::Display] and [std::error::Error], as asked
+    /// through ```error_enum_impls```, or ```#[error_enum_impls]``` on the TryFrom derive.
+    pub(crate) error_enum_impls: bool,
+    /// How errors are returned, as asked through ```errors(...)```, or ```#[errors(...)]``` on the
+    /// TryFrom derive.
+    pub(crate) errors_mode: ErrorsMode,
+    /// Error enum shared with other constructors, as given through ```error_enum(...)```, or
+    /// ```#[error_enum(...)]``` on the TryFrom derive, in which case it's named
+    /// [TryFromInfo::error_enum_name].
+    pub(crate) shared_error_enum: Option<SharedErrorEnum>,
     pub(crate) error_enum_name: Ident,
     pub(crate) error_types: Vec<Ident>,
     pub(crate) try_from_types: Vec<Ident>,
 }
 
 impl TryFromInfo {
-    fn error_types_and_try_from_types(fields_names: &Vec<Ident>) -> (Vec<Ident>, Vec<Ident>) {
-        let error_types = fields_names.iter()
-            .map(|field_name|
+    /// Names of the variants holding the error of each field, which are also the names of the
+    /// generic parameters of said errors, and of the generic parameters of the values received for
+    /// each field.<br>
+    /// Variants are named through ```#[error_variant(...)]``` or after the template given through
+    /// ```error_variants_named(...)```, where ```{Field}``` stands for the field's name in
+    /// PascalCase, which is ```{Field}Error``` by default.<br>
+    /// These can't be named like the variants of the fields' checks, nor ```ValidationError```, or
+    /// ```RowLength``` when the FromStr pattern parses rows.
+    fn error_types_and_try_from_types(fields_info: &FieldsInfo, variants_template: Option<String>, parses_rows: bool) -> (Vec<Ident>, Vec<Ident>) {
+        let fields_names = &fields_info.fields_names;
+        let variants_template = variants_template.unwrap_or_else(|| "{Field}Error".to_string());
+        let error_types = fields_names.iter().zip(fields_info.fields_error_variants.iter())
+            .map(|(field_name, error_variant)| error_variant.clone().unwrap_or_else(||
                 syn::parse_str::<Ident>(
-                    &format!("{}Error", field_name.to_string().to_case(Case::Pascal))
-                ).expect_else(|_| format!("Could not create enum error's identifier name for field {field_name}")))
+                    &variants_template.replace("{Field}", &field_name.to_string().to_case(Case::Pascal))
+                ).expect_else(|_| format!("Could not create enum error's identifier name for field {field_name}"))))
+            .collect::<Vec<_>>();
+        let check_variants = fields_names.iter().zip(fields_info.fields_validations.iter())
+            .flat_map(|(field_name, validations)| validations.iter().map(|validation| validation.variant(field_name)))
             .collect::<Vec<_>>();
+        error_types.iter().enumerate()
+            .for_each(|(index, error_type)| {
+                if error_types[..index].contains(error_type) || check_variants.contains(error_type)
+                    || error_type == "ValidationError" || (parses_rows && error_type == "RowLength") {
+                    panic!("The error enum would have more than one variant named '{error_type}', consider renaming it through #[error_variant(...)]")
+                }
+            });
 
         let try_from_types = fields_names.iter()
             .map(|field_name|
@@ -111,46 +607,313 @@ impl TryFromInfo {
         (error_types, try_from_types)
     }
 
-    pub(crate) fn new_from_derive_data_struct(name: &Ident, attrs: &Vec<Attribute>, fields_names: &Vec<Ident>) -> TryFromInfo {
-        let error_enum_metadata: proc_macro2::TokenStream = find_attribute(&attrs, "enum_error_meta")
+    /// Reads the template given through ```error_variants_named("Invalid{Field}")```.
+    fn variants_template(tokens: proc_macro2::TokenStream) -> String {
+        let template = syn::parse2::<syn::LitStr>(tokens)
+            .expect_else(|_| "Could not parse 'error_variants_named', it should be given a string, like 'error_variants_named(\"Invalid{Field}\")'")
+            .value();
+        if !template.contains("{Field}") {
+            panic!("The template '{template}' given to 'error_variants_named' doesn't contain '{{Field}}', so every variant would have the same name")
+        }
+        template
+    }
+
+    /// Reads the mode given through ```errors(...)```.
+    fn errors_mode(mode: proc_macro2::TokenStream) -> ErrorsMode {
+        match mode.to_string().as_str() {
+            "collect" => ErrorsMode::Collect,
+            "return_inputs" => ErrorsMode::ReturnInputs,
+            wrong_mode => panic!("Unknown errors mode '{wrong_mode}', the modes available are 'collect' and 'return_inputs', as in 'errors(collect)'"),
+        }
+    }
+
+    /// Replaces the error enum with the shared one given through ```error_enum(...)```, if any.
+    fn sharing_error_enum(mut self, shared_error_enum: Option<(Ident, SharedErrorEnum)>, has_variants_template: bool) -> Self {
+        let Some((error_enum_name, shared_error_enum)) = shared_error_enum else {
+            return self;
+        };
+        if has_variants_template {
+            panic!("Error enum {error_enum_name} is shared, so every constructor sharing it must agree on the names of its variants, which can't be given through 'error_variants_named', consider naming them through #[error_variant(...)]")
+        }
+        if self.errors_mode != ErrorsMode::FirstError {
+            panic!("Error enum {error_enum_name} is shared, which can't be combined with 'errors(...)', as these return their own types")
+        }
+        if !shared_error_enum.defines && (!self.error_enum_metadata.is_empty() || self.error_enum_impls) {
+            panic!("Error enum {error_enum_name} is declared by the constructor defining it, so its metadata and impls are only given there, consider moving 'error_enum_metadata' and 'error_enum_impls' to it")
+        }
+        self.error_enum_name = error_enum_name;
+        self.shared_error_enum = Some(shared_error_enum);
+        self
+    }
+
+    pub(crate) fn new_from_derive_data_struct(name: &Ident, attrs: &[Attribute], data: &DataStruct, generics: &Generics, fields_info: &FieldsInfo) -> TryFromInfo {
+        let error_enum_metadata: proc_macro2::TokenStream = find_attribute(attrs, "enum_error_meta")
             .map(|attribute| extract_token_stream_of_attribute(attribute)
                 .expect_else(|| "Could not parse content of the #[enum_error_meta] attribute"))
-            .unwrap_or_else(|| TokenStream::new()).into();
+            .unwrap_or_default().into();
 
         let error_enum_name = syn::parse_str::<Ident>
             (&format!("{}TryFromError", name.to_string().to_case(Case::Pascal)))
-            .expect_else(|_| format!("Could not create enum error's identifier name"));
+            .expect_else(|_| "Could not create enum error's identifier name");
 
-        let (error_types, try_from_types) = Self::error_types_and_try_from_types(fields_names);
+        let variants_template = find_attribute(attrs, "error_variants_named")
+            .map(|attribute| Self::variants_template(extract_token_stream_of_attribute(attribute)
+                .expect_else(|| "Could not parse content of the #[error_variants_named] attribute").into()));
+        let has_variants_template = variants_template.is_some();
+        let (error_types, try_from_types) = Self::error_types_and_try_from_types(fields_info, variants_template, false);
+        let shared_error_enum = find_attribute(attrs, "error_enum")
+            .map(|attribute| SharedErrorEnum::parse(extract_token_stream_of_attribute(attribute)
+                .expect_else(|| "Could not parse content of the #[error_enum] attribute").into(), data, generics));
 
         Self {
             error_enum_metadata,
+            error_enum_impls: find_attribute(attrs, "error_enum_impls").is_some(),
+            errors_mode: find_attribute(attrs, "errors")
+                .map(|attribute| Self::errors_mode(attribute.tokens.to_token_stream().into_iter()
+                    .filter_map(|token| match token {
+                        proc_macro2::TokenTree::Group(group) => Some(group.stream()),
+                        _ => None,
+                    })
+                    .next().unwrap_or_default()))
+                .unwrap_or(ErrorsMode::FirstError),
+            shared_error_enum: None,
             error_enum_name,
             error_types,
             try_from_types,
-        }
+        }.sharing_error_enum(shared_error_enum, has_variants_template)
     }
 
 
-    pub(crate) fn new_from_macro_attribute_info(derive_input: &DeriveInput, fields_info: &FieldsInfo, constructor_fn_name: Option<&Ident>, attr_contents: &mut HashMap<String, proc_macro2::TokenStream>) -> Self {
+    /// Reads the options of a TryFrom or FromStr constructor, the latter parsing rows, as told by
+    /// ```parses_rows```.
+    pub(crate) fn new_from_macro_attribute_info(derive_input: &DeriveInput, fields_info: &FieldsInfo, constructor_fn_name: Option<&Ident>, parses_rows: bool, attr_contents: &mut HashMap<String, proc_macro2::TokenStream>) -> Self {
         let error_enum_metadata = attr_contents.remove("error_enum_metadata")
-            .unwrap_or_else(proc_macro2::TokenStream::new);
+            .unwrap_or_default();
+        let is_named = attr_contents.contains_key("error_enum_named");
         let error_enum_name = attr_contents.remove("error_enum_named")
             .map(|name| syn::parse::<Ident>(name.into()).unwrap())
             .unwrap_or_else(|| {
                 let constructor_fn_name = constructor_fn_name.map(|constructor_name| constructor_name.to_string()).unwrap_or_else(|| "TryFrom".to_string());
                 syn::parse_str::<Ident>
                     (&format!("{}_{}_error", derive_input.ident, constructor_fn_name).to_case(Case::Pascal))
-                    .expect_else(|_| format!("Could not create enum error's identifier name"))
+                    .expect_else(|_| "Could not create enum error's identifier name")
+            });
+        let variants_template = attr_contents.remove("error_variants_named").map(Self::variants_template);
+        let has_variants_template = variants_template.is_some();
+        let (error_types, try_from_types) = Self::error_types_and_try_from_types(fields_info, variants_template, parses_rows);
+        if attr_contents.contains_key("error_enum") && is_named {
+            panic!("The error enum of a constructor given through 'error_enum' is named there, consider removing 'error_enum_named'")
+        }
+        let shared_error_enum = attr_contents.remove("error_enum")
+            .map(|tokens| match &derive_input.data {
+                Data::Struct(data) => SharedErrorEnum::parse(tokens, data, &derive_input.generics),
+                _ => unreachable!("Constructors are only created for structs"),
             });
-        let (error_types, try_from_types) = Self::error_types_and_try_from_types(&fields_info.fields_names);
 
         Self {
             error_enum_metadata,
+            error_enum_impls: attr_contents.remove("error_enum_impls").is_some(),
+            errors_mode: attr_contents.remove("errors").map(Self::errors_mode).unwrap_or(ErrorsMode::FirstError),
+            shared_error_enum: None,
             error_enum_name,
             error_types,
             try_from_types,
+        }.sharing_error_enum(shared_error_enum, has_variants_template)
+    }
+}
+
+/// Error enum shared by several TryFrom constructors, declared through
+/// ```error_enum(define = Name)``` on one of them and used through ```error_enum(use = Name)``` on
+/// the rest, which holds a variant for every field any constructor might receive, along with
+/// their checks and the validator's error, without generic parameters, so every constructor
+/// returns the same type.
+pub(crate) struct SharedErrorEnum {
+    /// Whether this constructor declares the enum, rather than using the one another declares.
+    pub(crate) defines: bool,
+    /// Fields any constructor might receive, see [FieldsInfo::new_for_every_field].
+    pub(crate) fields_info: FieldsInfo,
+    /// Variants holding the error of each of these fields, along with said error's type, which is
+    /// only there when it can be named out of the field alone, this is, when the field declares
+    /// the type it's received as through ```#[try_from(u16)]```, or a converter that may fail
+    /// through ```#[convert(...)]```, neither depending on the struct's generics.
+    pub(crate) variants: Vec<(Ident, Option<proc_macro2::TokenStream>)>,
+}
+
+impl SharedErrorEnum {
+    fn parse(tokens: proc_macro2::TokenStream, data: &DataStruct, generics: &Generics) -> (Ident, Self) {
+        let parser = |input: ParseStream| {
+            let mode = <Ident as syn::ext::IdentExt>::parse_any(input)?;
+            input.parse::<syn::Token![=]>()?;
+            let name = input.parse::<Ident>()?;
+            input.parse::<Option<syn::Token![,]>>()?;
+            Ok((mode, name))
+        };
+        let (mode, name) = parser.parse2(tokens)
+            .expect_else(|_| "Could not parse 'error_enum', it should be given the name of the enum, like 'error_enum(define = CharacterError)' or 'error_enum(use = CharacterError)'");
+        let defines = match mode.to_string().as_str() {
+            "define" => true,
+            "use" => false,
+            wrong_mode => panic!("Unknown error_enum mode '{wrong_mode}', it should be either 'define' or 'use', like 'error_enum(use = {name})'"),
+        };
+        let fields_info = FieldsInfo::new_for_every_field(data, generics);
+        let (variants, _) = TryFromInfo::error_types_and_try_from_types(&fields_info, None, false);
+        let variants_types = fields_info.fields_types.iter().zip(fields_info.fields_converters.iter()).zip(fields_info.fields_input_types.iter())
+            .map(|((field_type, converter), input_type)| match (converter, input_type) {
+                (Some(converter), _) => converter.error.as_ref()
+                    .filter(|error| !type_uses_generics(error, generics))
+                    .map(ToTokens::to_token_stream),
+                (None, Some(input_type)) if !type_uses_generics(field_type, generics) && !type_uses_generics(input_type, generics) => {
+                    let input_type = with_static_lifetimes(input_type);
+                    Some(quote!(<#field_type as core::convert::TryFrom<#input_type>>::Error))
+                }
+                (None, _) => None,
+            });
+        let variants = variants.into_iter().zip(variants_types).collect();
+        (name, Self { defines, fields_info, variants })
+    }
+}
+
+pub(crate) struct BuilderInfo {
+    pub(crate) builder_name: Ident,
+    pub(crate) error_enum_metadata: proc_macro2::TokenStream,
+    pub(crate) error_enum_name: Ident,
+    /// Variants of the error enum telling a field that had to be given wasn't, like ```MissingAge```.
+    pub(crate) missing_field_variants: Vec<Ident>,
+}
+
+impl BuilderInfo {
+    pub(crate) fn new_from_macro_attribute_info(derive_input: &DeriveInput, fields_info: &FieldsInfo, attr_contents: &mut HashMap<String, proc_macro2::TokenStream>) -> Self {
+        let builder_name = attr_contents.remove("builder_named")
+            .map(|name| syn::parse::<Ident>(name.into()).expect_else(|_| "Could not get name for the builder"))
+            .unwrap_or_else(|| format_ident!("{}Builder", derive_input.ident));
+        let error_enum_metadata = attr_contents.remove("error_enum_metadata")
+            .unwrap_or_default();
+        let error_enum_name = attr_contents.remove("error_enum_named")
+            .map(|name| syn::parse::<Ident>(name.into()).expect_else(|_| "Could not get name for the builder's error enum"))
+            .unwrap_or_else(|| format_ident!("{builder_name}Error"));
+        let missing_field_variants = fields_info.fields_names.iter()
+            .map(|field_name| format_ident!("Missing{}", field_name.to_string().to_case(Case::Pascal)))
+            .collect();
+
+        Self {
+            builder_name,
+            error_enum_metadata,
+            error_enum_name,
+            missing_field_variants,
+        }
+    }
+}
+
+/// Options applying to the constructor as a whole, whichever its pattern is.
+pub(crate) struct ConstructorInfo {
+    /// Function checking the struct once it's built, see [Validator].
+    pub(crate) validator: Option<Validator>,
+    /// Visibility of the constructor function, and for builders, of the builder and its functions.
+    pub(crate) vis: Visibility,
+    /// Visibility of the error enum, which is the same as [ConstructorInfo::vis] unless specified.
+    pub(crate) error_enum_vis: Visibility,
+    /// Whether the constructor function is a ```const fn```.
+    pub(crate) is_const: bool,
+    /// Summary documenting the constructor function, replacing the generated one.
+    pub(crate) doc: Option<String>,
+    /// Attributes for the constructor function, like ```#[must_use]```.
+    pub(crate) fn_attrs: proc_macro2::TokenStream,
+    /// Attributes for every generated impl block, like ```#[cfg(feature = "constructors")]```.
+    pub(crate) impl_attrs: proc_macro2::TokenStream,
+    /// The ```#[cfg(...)]``` attributes of the struct, which are copied to every generated item, as
+    /// they can't exist when the struct doesn't.
+    pub(crate) cfg_attrs: proc_macro2::TokenStream,
+}
+
+impl ConstructorInfo {
+    pub(crate) fn new_from_derive_data_struct(attrs: &[Attribute], struct_vis: &Visibility) -> Self {
+        let attribute_content = |attribute_name: &str| find_attribute(attrs, attribute_name)
+            .map(|attribute| extract_token_stream_of_attribute(attribute)
+                .expect_else(|| format!("Could not parse content of the #[{attribute_name}] attribute")))
+            .map(Into::into)
+            .unwrap_or_default();
+        let validator = find_attribute(attrs, "validate")
+            .map(|attribute| extract_token_stream_of_attribute(attribute)
+                .expect_else(|| "Could not parse content of the #[validate] attribute"))
+            .map(|tokens| Validator::parse(tokens.into()));
+        let error_enum_vis = find_attribute(attrs, "error_enum_vis")
+            .map(|attribute| extract_token_stream_of_attribute(attribute).unwrap_or_default())
+            .map(|tokens| Self::parse_visibility(tokens.into(), struct_vis, "error_enum_vis"))
+            .unwrap_or_else(|| parse_quote!(pub));
+        Self {
+            validator,
+            vis: parse_quote!(pub),
+            error_enum_vis,
+            is_const: false,
+            doc: None,
+            fn_attrs: attribute_content("fn_attrs"),
+            impl_attrs: attribute_content("impl_attrs"),
+            cfg_attrs: Self::cfg_attrs_of(attrs),
+        }
+    }
+
+    pub(crate) fn new_from_macro_attribute_info(derive_input: &DeriveInput, attr_contents: &mut HashMap<String, proc_macro2::TokenStream>) -> Self {
+        let struct_vis = &derive_input.vis;
+        let validator = attr_contents.remove("validate")
+            .map(Validator::parse);
+        let vis = attr_contents.remove("vis")
+            .map(|vis| Self::parse_visibility(vis, struct_vis, "vis"))
+            .unwrap_or_else(|| parse_quote!(pub));
+        let error_enum_vis = attr_contents.remove("error_enum_vis")
+            .map(|vis| Self::parse_visibility(vis, struct_vis, "error_enum_vis"))
+            .unwrap_or_else(|| vis.clone());
+        let is_const = attr_contents.remove("const").is_some();
+        let doc = attr_contents.remove("doc")
+            .map(|doc| syn::parse2::<syn::LitStr>(doc)
+                .expect_else(|_| "Could not parse 'doc', it should be given a string, like 'doc(\"Creates a character.\")'")
+                .value());
+        Self {
+            validator,
+            vis,
+            error_enum_vis,
+            is_const,
+            doc,
+            fn_attrs: attr_contents.remove("fn_attrs").unwrap_or_default(),
+            impl_attrs: attr_contents.remove("impl_attrs").unwrap_or_default(),
+            cfg_attrs: Self::cfg_attrs_of(&derive_input.attrs),
         }
     }
+
+    fn cfg_attrs_of(attrs: &[Attribute]) -> proc_macro2::TokenStream {
+        let cfg_attrs = attrs.iter()
+            .filter(|attribute| attribute.path.is_ident("cfg"));
+        quote!(#(#cfg_attrs)*)
+    }
+
+    /// Parses a visibility like ```pub(crate)```, where ```inherit``` stands for the struct's
+    /// visibility and nothing at all for private.
+    fn parse_visibility(tokens: proc_macro2::TokenStream, struct_vis: &Visibility, attribute_name: &str) -> Visibility {
+        if tokens.to_string() == "inherit" {
+            return struct_vis.clone();
+        }
+        syn::parse2::<Visibility>(tokens)
+            .expect_else(|_| format!("Could not parse the visibility of attribute '{attribute_name}', it should be like 'pub', 'pub(crate)' or 'inherit'"))
+    }
 }
 
+/// Function given through ```validate(path::to::function, ErrorType)``` which gets called with a
+/// reference to the struct once it's built, like ```fn(&YourStruct) -> Result<(), ErrorType>```,
+/// where returning [Err] makes the constructor fail with said error.
+pub(crate) struct Validator {
+    pub(crate) function: Path,
+    pub(crate) error_type: Type,
+}
+
+impl Validator {
+    fn parse(tokens: proc_macro2::TokenStream) -> Self {
+        let parser = |input: ParseStream| {
+            let function = input.parse::<Path>()?;
+            input.parse::<syn::Token![,]>()?;
+            let error_type = input.parse::<Type>()?;
+            input.parse::<Option<syn::Token![,]>>()?;
+            Ok(Validator { function, error_type })
+        };
+        parser.parse2(tokens)
+            .expect_else(|_| "Could not parse 'validate', it should be given a function and the type of its error, like 'validate(path::to::function, ErrorType)'")
+    }
+}
@@ -1,8 +1,9 @@
 use proc_macro::{TokenStream, TokenTree};
-use proc_macro2::{Ident, Punct};
-use quote::quote;
-use syn::{Attribute, Error, Variant};
-use syn::parse::ParseStream;
+use proc_macro2::Ident;
+use syn::{Attribute, DeriveInput, GenericParam, Generics, Member, Path, Type};
+use syn::punctuated::Punctuated;
+use syn::parse::{ParseStream, Parser};
+use quote::ToTokens;
 use std::iter::zip;
 
 pub(crate) fn print_info<TNameRet, TInfoRet, TName, TInfo>(_name: TName, _info: TInfo)
@@ -22,14 +23,9 @@ pub(crate) trait ExpectElseResult<T, E> {
 
 impl<T, E: core::fmt::Debug> ExpectElseResult<T, E> for Result<T, E> {
     fn expect_else<TInfoRet: ToString, TInfo: FnOnce(&E) -> TInfoRet>(self, info: TInfo) -> T {
-        if self.is_ok() {
-            self.expect("")
-        } else {
-            let error_info = match &self {
-                Err(error) => info(error).to_string(),
-                _ => { panic!("Unreachable point"); }
-            };
-            self.expect(&error_info)
+        match self {
+            Ok(value) => value,
+            Err(error) => panic!("{}: {error:?}", info(&error).to_string()),
         }
     }
 }
@@ -41,10 +37,9 @@ pub(crate) trait ExpectElseOption<T> {
 
 impl<T> ExpectElseOption<T> for Option<T> {
     fn expect_else<TInfoRet: ToString, TInfo: FnOnce() -> TInfoRet>(self, info: TInfo) -> T {
-        if self.is_some() {
-            self.expect("")
-        } else {
-            self.expect(&info().to_string())
+        match self {
+            Some(value) => value,
+            None => panic!("{}", info().to_string()),
         }
     }
 }
@@ -59,87 +54,443 @@ pub(crate) fn extract_token_stream_of_attribute(variants_value_attr: &Attribute)
     token_stream
 }
 
-pub(crate) fn fields_as_const_defaults_tokens(variant: &Variant) -> Option<proc_macro2::TokenStream> {
-    let internal_fields_as_default = variant.fields
-        .iter()
-        .map(|field| {
-            field.ident.as_ref()
-                .map(|field_name| quote!(#field_name (const_default::ConstDefault::DEFAULT)))
-                .unwrap_or_else(|| quote!((const_default::ConstDefault::DEFAULT)))
+pub(crate) fn find_attribute<'attr>(attrs: &'attr [Attribute], attribute_ident: &str) -> Option<&'attr Attribute> {
+    attrs.iter()
+        .find(|attribute| attribute.path.is_ident(attribute_ident))
+}
+
+/// Documentation given through ```///``` comments, or ```#[doc = "..."]``` attributes, keeping its
+/// lines, along with the indentation they have past the one common to all of them.
+pub(crate) fn doc_of(attrs: &[Attribute]) -> String {
+    let lines = attrs.iter()
+        .filter(|attribute| attribute.path.is_ident("doc"))
+        .filter_map(|attribute| match attribute.parse_meta() {
+            Ok(syn::Meta::NameValue(syn::MetaNameValue { lit: syn::Lit::Str(doc), .. })) => Some(doc.value()),
+            _ => None,
         })
-        .reduce(|prev_token, next_token| quote!(#prev_token, #next_token));
-    internal_fields_as_default
+        .flat_map(|doc| doc.lines().map(str::to_string).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let indentation = lines.iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or_default();
+    lines.iter()
+        .map(|line| line.get(indentation..).unwrap_or_default().trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_matches('\n')
+        .to_string()
 }
 
-pub(crate) fn parse_separated_idents(input: ParseStream) -> Result<Vec<Ident>, Error> {
-    let mut idents = Vec::new();
-    while !input.is_empty() {
-        match input.parse::<Ident>() {
-            Ok(ident) => idents.push(ident),
-            Err(_) => {
-                if input.parse::<Punct>().is_err() {
-                    return Err(Error::new(input.span(), "Not a feature or a punctuation sign"));
+/// Readable form of some tokens for documentation, as in ```Vec<u8>``` rather than the
+/// ```Vec < u8 >``` [ToString] gives, which are printed through [prettyplease] when they are a type
+/// or an expression, and written as they are otherwise.
+pub(crate) fn pretty_tokens<T: ToTokens>(tokens: &T) -> String {
+    let tokens = tokens.to_token_stream();
+    let (item, prefix) = match syn::parse2::<Type>(tokens.clone()) {
+        Ok(ty) => (syn::parse_quote!(type Pretty = #ty;), "type Pretty = "),
+        Err(_) => match syn::parse2::<syn::Expr>(tokens.clone()) {
+            Ok(expr) => (syn::parse_quote!(const PRETTY: () = #expr;), "const PRETTY: () = "),
+            Err(_) => return tokens.to_string(),
+        },
+    };
+    let pretty = prettyplease::unparse(&syn::File { shebang: None, attrs: Vec::new(), items: vec![item] });
+    pretty.trim_end()
+        .strip_prefix(prefix)
+        .and_then(|pretty| pretty.strip_suffix(';'))
+        .map(|pretty| pretty.lines().map(str::trim).collect::<Vec<_>>().join(" "))
+        .unwrap_or_else(|| tokens.to_string())
+}
+
+/// Fields given through the ```fields``` option, like ```fields(name, age)```, along with the
+/// types they are received as when declared, like ```u16``` in ```fields(name, age: u16)```.
+pub(crate) fn fields_of_option(option: proc_macro2::TokenStream) -> Vec<(Member, Option<Type>)> {
+    let parser = |input: ParseStream| {
+        let mut fields = Vec::new();
+        while !input.is_empty() {
+            let member = input.parse::<Member>()?;
+            let input_type = match input.peek(syn::Token![:]) {
+                true => {
+                    input.parse::<syn::Token![:]>()?;
+                    Some(input.parse::<Type>()?)
+                }
+                false => None,
+            };
+            fields.push((member, input_type));
+            if !input.is_empty() {
+                input.parse::<syn::Token![,]>()?;
+            }
+        }
+        Ok(fields)
+    };
+    parser.parse2(option)
+        .expect_else(|_| "Could not parse the 'fields' attribute, it should look like 'fields(name, age)', or 'fields(name, age: u16)' to declare the types they are received as")
+}
+
+/// The given type where elided lifetimes of references, and ```'_```, are ```'static```, as in
+/// ```&'static str``` for ```&str```, so it can be written where lifetimes can't be elided, like
+/// an enum's variant.<br>
+/// Lifetimes hidden by a path, like the one of ```std::str::Chars```, aren't seen here, so they
+/// must be written as ```Chars<'_>```.
+pub(crate) fn with_static_lifetimes(ty: &Type) -> Type {
+    fn replace(tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let tokens = tokens.into_iter().collect::<Vec<_>>();
+        let mut replaced = Vec::new();
+        for (index, token) in tokens.iter().enumerate() {
+            let next = tokens.get(index + 1);
+            match token {
+                proc_macro2::TokenTree::Group(group) => {
+                    let mut new_group = proc_macro2::Group::new(group.delimiter(), replace(group.stream()));
+                    new_group.set_span(group.span());
+                    replaced.push(new_group.into());
                 }
+                proc_macro2::TokenTree::Punct(punct) if punct.as_char() == '&'
+                    && !next.is_some_and(|next| matches!(next, proc_macro2::TokenTree::Punct(next) if next.as_char() == '\'')) => {
+                    replaced.push(token.clone());
+                    replaced.extend(quote::quote!('static));
+                }
+                proc_macro2::TokenTree::Ident(ident) if ident == "_" && index > 0
+                    && matches!(&tokens[index - 1], proc_macro2::TokenTree::Punct(previous) if previous.as_char() == '\'') => {
+                    replaced.push(Ident::new("static", ident.span()).into());
+                }
+                _ => replaced.push(token.clone()),
             }
         }
+        replaced.into_iter().collect()
     }
-    Ok(idents)
+    syn::parse2(replace(ty.to_token_stream()))
+        .expect_else(|_| format!("Could not give static lifetimes to type '{}'", ty.to_token_stream()))
 }
 
-pub(crate) fn find_attribute_last_in_path<'attr>(attrs: &'attr Vec<Attribute>, attribute_ident: &str) -> Option<&'attr Attribute> {
-    attrs.iter()
-        .filter(|attribute| attribute.path.segments.iter().last().is_some_and(|segment| segment.ident.to_string().eq(attribute_ident)))
-        .next()
+/// Standard derives, like ```Debug``` or ```PartialEq```, declared on the metadata of an error
+/// enum, which are the only metadata also given to the structs holding it, as others, like
+/// ```#[repr(u8)]```, might only apply to enums.
+pub(crate) fn standard_derives(metadata: &proc_macro2::TokenStream) -> Vec<Path> {
+    const STANDARD_DERIVES: &[&str] = &["Debug", "Clone", "Copy", "PartialEq", "Eq", "PartialOrd", "Ord", "Hash"];
+    Attribute::parse_outer.parse2(metadata.clone())
+        .expect_else(|_| format!("Could not parse the error enum's metadata '{metadata}'"))
+        .into_iter()
+        .filter(|attribute| attribute.path.is_ident("derive"))
+        .flat_map(|attribute| attribute.parse_args_with(Punctuated::<Path, syn::Token![,]>::parse_terminated).unwrap_or_default())
+        .filter(|derive| derive.segments.last().is_some_and(|segment| STANDARD_DERIVES.iter().any(|standard| segment.ident == standard)))
+        .collect()
 }
 
-pub(crate) fn find_attribute<'attr>(attrs: &'attr Vec<Attribute>, attribute_ident: &str) -> Option<&'attr Attribute> {
-    attrs.iter()
-        .filter(|attribute| attribute.path.is_ident(attribute_ident))
-        .next()
+/// Field attributes read by this crate's macros, which are declared as helpers by the From and
+/// TryFrom derives.
+pub(crate) const FIELD_HELPER_ATTRIBUTES: &[&str] = &["no_from", "into", "try_from", "exact", "convert", "validate", "error_variant"];
+
+/// Removes this crate's helper attributes from the fields of a struct, as the compiler would reject
+/// them when the From and TryFrom derives aren't there to declare them.<br>
+/// This is only done when no other macro of this crate will still read them, this is, when there
+/// isn't another ```#[constructor]``` or ```#[constructors]``` attribute nor a From or TryFrom derive left on the struct.
+pub(crate) fn strip_helper_attributes(derive_input: &mut DeriveInput) {
+    let helpers_still_in_use = derive_input.attrs.iter()
+        .any(|attribute| {
+            let is_constructor = attribute.path.segments.last().is_some_and(|segment| segment.ident == "constructor" || segment.ident == "constructors");
+            let derives_helpers = attribute.path.is_ident("derive") && attribute
+                .parse_args_with(Punctuated::<Path, syn::Token![,]>::parse_terminated)
+                .is_ok_and(|derives| derives.iter().any(is_own_derive));
+            is_constructor || derives_helpers
+        });
+    if helpers_still_in_use {
+        return;
+    }
+    if let syn::Data::Struct(data) = &mut derive_input.data {
+        data.fields.iter_mut()
+            .for_each(|field| field.attrs.retain(|attribute| !FIELD_HELPER_ATTRIBUTES.iter().any(|helper| attribute.path.is_ident(helper))));
+    }
 }
 
-pub fn idents_and_groups_from<TTokenStream: Into<TokenStream>>(token_stream: TTokenStream) -> Result<Vec<(Ident, proc_macro2::TokenStream)>, syn::Error> {
+/// Tells whether a derive's path is this crate's From or TryFrom, this is, a bare ```From``` or
+/// ```TryFrom```, or one given through this crate, as in ```derive_constructors::From```, while other
+/// crate's derives, like ```derive_more::From```, don't count.
+fn is_own_derive(derive: &Path) -> bool {
+    let segments = derive.segments.iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>();
+    match segments.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        ["From" | "TryFrom"] => derive.leading_colon.is_none(),
+        ["derive_constructors" | "derive_constructors_proc", "From" | "TryFrom"] => true,
+        _ => false,
+    }
+}
+
+/// Tells whether a type is a [core::marker::PhantomData], these fields are never received as
+/// parameters, as they can only be initialized in one way.<br>
+/// Only ```PhantomData```, ```marker::PhantomData```, and ```core::marker::PhantomData``` or
+/// ```std::marker::PhantomData``` count as such, so a user's type named ```PhantomData``` inside
+/// another module is still asked for.
+pub(crate) fn is_phantom_data(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    if type_path.qself.is_some() {
+        return false;
+    }
+    let segments = type_path.path.segments.iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>();
+    match segments.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        ["PhantomData"] | ["marker", "PhantomData"] => type_path.path.leading_colon.is_none(),
+        ["core" | "std", "marker", "PhantomData"] => true,
+        _ => false,
+    }
+}
+
+/// Tells whether a type has a type parameter of the given generics which isn't covered by another
+/// type, as in ```T```, ```&'a T``` or ```Box<T>```, where references, [Box] and
+/// [Pin](core::pin::Pin) don't cover them as they are fundamental, so Rust doesn't allow
+/// implementing foreign traits for them, while ```Vec<T>``` covers ```T```.<br>
+/// Qualified paths, like ```<T as Trait>::Output```, count as uncovered when they use the generics.
+pub(crate) fn has_uncovered_type_parameter(ty: &Type, generics: &Generics) -> bool {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_some() => type_uses_generics(ty, generics),
+        Type::Path(type_path) => {
+            if generics.type_params().any(|type_param| type_path.path.is_ident(&type_param.ident)) {
+                return true;
+            }
+            let Some(last_segment) = type_path.path.segments.last() else {
+                return false;
+            };
+            let syn::PathArguments::AngleBracketed(arguments) = &last_segment.arguments else {
+                return false;
+            };
+            (last_segment.ident == "Box" || last_segment.ident == "Pin") && arguments.args.iter()
+                .any(|argument| matches!(argument, syn::GenericArgument::Type(argument) if has_uncovered_type_parameter(argument, generics)))
+        }
+        Type::Reference(reference) => has_uncovered_type_parameter(&reference.elem, generics),
+        Type::Paren(paren) => has_uncovered_type_parameter(&paren.elem, generics),
+        Type::Group(group) => has_uncovered_type_parameter(&group.elem, generics),
+        _ => false,
+    }
+}
+
+/// Tells whether a type mentions any of the type or const parameters of the given generics, for
+/// example, ```Vec<T>``` uses ```T``` of ```struct Wrapper<T>```.
+pub(crate) fn type_uses_generics(ty: &Type, generics: &Generics) -> bool {
+    let generic_idents = generics.params.iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(type_param) => Some(type_param.ident.clone()),
+            GenericParam::Const(const_param) => Some(const_param.ident.clone()),
+            GenericParam::Lifetime(_) => None,
+        })
+        .collect::<Vec<_>>();
+    tokens_contain_any_ident(ty.to_token_stream(), &generic_idents)
+}
+
+/// Tells whether a type mentions any parameter of the given generics, lifetimes included, as in
+/// ```&'a str``` for ```struct Wrapper<'a>```.
+pub(crate) fn type_uses_generics_or_lifetimes(ty: &Type, generics: &Generics) -> bool {
+    let generic_idents = generics.params.iter()
+        .map(|param| match param {
+            GenericParam::Type(type_param) => type_param.ident.clone(),
+            GenericParam::Const(const_param) => const_param.ident.clone(),
+            GenericParam::Lifetime(lifetime) => lifetime.lifetime.ident.clone(),
+        })
+        .collect::<Vec<_>>();
+    tokens_contain_any_ident(ty.to_token_stream(), &generic_idents)
+}
+
+/// An identifier named like the given one that doesn't appear among the given tokens, prefixing it
+/// with underscores until it doesn't, so generated type parameters, like ```NameState```, don't
+/// collide with the struct's generics nor shadow the types of its fields.
+pub(crate) fn ident_not_in(name: &str, tokens: &proc_macro2::TokenStream) -> Ident {
+    let mut ident = Ident::new(name, proc_macro2::Span::call_site());
+    while tokens_contain_any_ident(tokens.clone(), std::slice::from_ref(&ident)) {
+        ident = Ident::new(&format!("_{ident}"), ident.span());
+    }
+    ident
+}
+
+fn tokens_contain_any_ident(tokens: proc_macro2::TokenStream, idents: &[Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => idents.contains(&ident),
+        proc_macro2::TokenTree::Group(group) => tokens_contain_any_ident(group.stream(), idents),
+        _ => false,
+    })
+}
+
+/// Tells whether an expression's tokens use a value under the given name, this is, an identifier
+/// named like it standing on its own, rather than being accessed through a path or a field access,
+/// as in ```x::name``` or ```x.name```, or being a path, a function or a macro itself, as in
+/// ```name::x```, ```name(x)``` or ```name![x]```, or the name being captured by a format string, as
+/// in ```"{name}"```.<br>
+/// Values bound under the name inside the expression shadow it where they are in scope, these are
+/// closures' parameters, as in ```|name| name.len()```, and the patterns of ```let``` and ```for```,
+/// as in ```{ let name = 1; name }``` or ```for name in names { name }```, while other bindings,
+/// like a match arm's, still count as using it.
+pub(crate) fn tokens_reference_ident(tokens: proc_macro2::TokenStream, ident: &Ident) -> bool {
+    tokens_slice_reference_ident(&tokens.into_iter().collect::<Vec<_>>(), ident)
+}
+
+fn tokens_slice_reference_ident(tokens: &[proc_macro2::TokenTree], ident: &Ident) -> bool {
+    let captured_in_format = [format!("{{{ident}}}"), format!("{{{ident}:")];
+    let token_at = |index: usize, offset: isize| index.checked_add_signed(offset).and_then(|index| tokens.get(index));
+    let position_from = |from: usize, found: &dyn Fn(&proc_macro2::TokenTree) -> bool| (from..tokens.len()).find(|&position| found(&tokens[position]));
+    let mut index = 0;
+    while let Some(token) = tokens.get(index) {
+        match token {
+            // Closures' parameters are only in scope within the closure's body, which ends at the
+            // first comma or semicolon, if any.
+            proc_macro2::TokenTree::Punct(punct) if punct.as_char() == '|' && starts_expression(index.checked_sub(1).map(|previous| &tokens[previous])) => {
+                let Some(closing) = position_from(index + 1, &|token| is_punct(Some(token), '|')) else {
+                    return false;
+                };
+                let body_end = position_from(closing + 1, &|token| is_punct(Some(token), ',') || is_punct(Some(token), ';')).unwrap_or(tokens.len());
+                if !pattern_binds_ident(&tokens[index + 1..closing], ident) && tokens_slice_reference_ident(&tokens[closing + 1..body_end], ident) {
+                    return true;
+                }
+                index = body_end;
+            }
+            // The patterns of 'if let', 'while let' and 'for' are only in scope within the block
+            // that follows them, while a 'let' statement's is in scope until the end of the block
+            // it's declared on.
+            proc_macro2::TokenTree::Ident(keyword) if keyword == "let" || keyword == "for" => {
+                let is_conditional = keyword == "for" || matches!(token_at(index, -1), Some(proc_macro2::TokenTree::Ident(previous)) if previous == "if" || previous == "while")
+                    || is_punct(token_at(index, -1), '&') && is_punct(token_at(index, -2), '&');
+                let pattern_end = (index + 1..tokens.len()).find(|&position| match &tokens[position] {
+                    proc_macro2::TokenTree::Ident(token_ident) => keyword == "for" && token_ident == "in",
+                    token => keyword == "let" && (is_punct(Some(token), '=')
+                        || is_punct(Some(token), ':') && !is_punct(token_at(position, -1), ':') && !is_punct(token_at(position, 1), ':')),
+                });
+                let Some(pattern_end) = pattern_end else {
+                    index += 1;
+                    continue;
+                };
+                let binds = pattern_binds_ident(&tokens[index + 1..pattern_end], ident);
+                let scope_start = match is_conditional {
+                    true => position_from(pattern_end, &|token| matches!(token, proc_macro2::TokenTree::Group(group) if group.delimiter() == proc_macro2::Delimiter::Brace)),
+                    false => position_from(pattern_end, &|token| is_punct(Some(token), ';')),
+                }.unwrap_or(tokens.len());
+                if tokens_slice_reference_ident(&tokens[pattern_end + 1..scope_start], ident) {
+                    return true;
+                }
+                match (binds, is_conditional) {
+                    (true, false) => return false,
+                    (true, true) => index = scope_start + 1,
+                    (false, _) => index = scope_start,
+                }
+            }
+            proc_macro2::TokenTree::Ident(token_ident) => {
+                let is_accessed = is_punct(token_at(index, -1), '.')
+                    || is_punct(token_at(index, -1), ':') && is_punct(token_at(index, -2), ':');
+                let is_path_or_call = is_punct(token_at(index, 1), ':') && is_punct(token_at(index, 2), ':')
+                    || is_punct(token_at(index, 1), '!') && matches!(token_at(index, 2), Some(proc_macro2::TokenTree::Group(_)))
+                    || matches!(token_at(index, 1), Some(proc_macro2::TokenTree::Group(group)) if group.delimiter() == proc_macro2::Delimiter::Parenthesis);
+                if token_ident == ident && !is_accessed && !is_path_or_call {
+                    return true;
+                }
+                index += 1;
+            }
+            proc_macro2::TokenTree::Literal(literal) => {
+                let literal = literal.to_string();
+                if literal.starts_with('"') && captured_in_format.iter().any(|capture| literal.contains(capture)) {
+                    return true;
+                }
+                index += 1;
+            }
+            proc_macro2::TokenTree::Group(group) => {
+                if tokens_reference_ident(group.stream(), ident) {
+                    return true;
+                }
+                index += 1;
+            }
+            proc_macro2::TokenTree::Punct(_) => index += 1,
+        }
+    }
+    false
+}
+
+fn is_punct(token: Option<&proc_macro2::TokenTree>, character: char) -> bool {
+    matches!(token, Some(proc_macro2::TokenTree::Punct(punct)) if punct.as_char() == character)
+}
+
+/// Tells whether a ```|``` following the given token starts a closure, rather than being a binary
+/// or, this is, when it doesn't follow a value.
+fn starts_expression(previous: Option<&proc_macro2::TokenTree>) -> bool {
+    match previous {
+        None | Some(proc_macro2::TokenTree::Punct(_)) => true,
+        Some(proc_macro2::TokenTree::Ident(previous)) => previous == "move" || previous == "return",
+        _ => false,
+    }
+}
+
+/// Tells whether a pattern binds a value under the given name, where identifiers following ```::```
+/// or followed by ```:```, ```!``` or a group, as in ```Some(name)``` or ```Point { name: x }```,
+/// are paths, fields or macros rather than bindings.
+fn pattern_binds_ident(pattern: &[proc_macro2::TokenTree], ident: &Ident) -> bool {
+    pattern.iter().enumerate().any(|(index, token)| match token {
+        proc_macro2::TokenTree::Ident(token_ident) => {
+            let follows_path = index >= 2 && is_punct(pattern.get(index - 1), ':') && is_punct(pattern.get(index - 2), ':');
+            let is_path_field_or_macro = is_punct(pattern.get(index + 1), ':')
+                || is_punct(pattern.get(index + 1), '!')
+                || matches!(pattern.get(index + 1), Some(proc_macro2::TokenTree::Group(_)));
+            token_ident == ident && !follows_path && !is_path_field_or_macro
+        }
+        proc_macro2::TokenTree::Group(group) => pattern_binds_ident(&group.stream().into_iter().collect::<Vec<_>>(), ident),
+        _ => false,
+    })
+}
+
+/// Splits a token stream like ```group1(desc), group2(desc)``` into its names and groups, names
+/// might also be a positional field's index, as in ```defaults(1(desc))```, and a name that isn't
+/// followed by a group counts as a flag, like ```from_unit``` in ```named(new), from_unit```,
+/// getting an empty group.<br>
+/// Names might be keywords, like ```const```.
+pub fn idents_and_groups_from<TTokenStream: Into<TokenStream>>(token_stream: TTokenStream) -> Result<Vec<(Member, proc_macro2::TokenStream)>, syn::Error> {
     let token_stream = token_stream.into();
-    let is_start = true;
     let mut parse_phase = ParsePhase::Ident;
     let mut idents = Vec::new();
     let mut groups = Vec::new();
     for token in token_stream.into_iter() {
-        match &parse_phase {
+        parse_phase = match &parse_phase {
             ParsePhase::Ident => match token {
-                TokenTree::Ident(ref ident) => idents.push(syn::parse::<Ident>(TokenStream::from(token)).unwrap()),
+                TokenTree::Ident(ident) => {
+                    idents.push(Member::Named(Ident::new(&ident.to_string(), ident.span().into())));
+                    ParsePhase::Group
+                }
+                TokenTree::Literal(_) => {
+                    idents.push(syn::parse::<Member>(TokenStream::from(token.clone()))
+                        .map_err(|_| syn::Error::new(token.span().into(), format!("Expected a name or a field's index, but found '{token}'")))?);
+                    ParsePhase::Group
+                }
                 _ => Err(syn::Error::new(token.span().into(),
                                          format!("Expected a name{}, for example, 'group_name' in 'group_name(my group's description)'",
-                                                 idents.last().map(|ident| format!(" after group named '{ident}'")).unwrap_or_else(|| String::new())
+                                                 idents.last().map(|ident| format!(" after group named '{}'", ident.to_token_stream())).unwrap_or_default()
                                          ),
                 ))?,
             },
             ParsePhase::Group => match token {
-                TokenTree::Group(group) => groups.push(group.stream().into()),
+                TokenTree::Group(group) => {
+                    groups.push(group.stream().into());
+                    ParsePhase::Punct
+                }
+                TokenTree::Punct(_) => {
+                    groups.push(proc_macro2::TokenStream::new());
+                    ParsePhase::Ident
+                }
                 _ => Err(syn::Error::new(token.span().into(),
                                          format!("Expected a group after group named '{}', for example, '(my group's description)' in 'group_name(my group's description)'",
-                                                 idents.last().unwrap()
+                                                 idents.last().unwrap().to_token_stream()
                                          ),
                 ))?,
             },
             ParsePhase::Punct => match token {
-                TokenTree::Punct(_) => {}
+                TokenTree::Punct(_) => ParsePhase::Ident,
                 _ => Err(syn::Error::new(token.span().into(),
                                          format!("Expected a separator after group named '{}', for example, the comma (',') in 'group1(desc), group2(desc)'",
-                                                 idents.last().unwrap()
+                                                 idents.last().unwrap().to_token_stream()
                                          ),
                 ))?,
             },
         };
-        parse_phase.advance_to_next();
     }
     if idents.len() != groups.len() {
-        let last_ident = idents.last().unwrap();
-        Err(syn::Error::new(last_ident.span().into(),
-                            "Expected a group, for example, '(my group's description)' in 'group_name(my group's description)'"))?
+        groups.push(proc_macro2::TokenStream::new());
     }
     let res = zip(idents, groups)
-        .into_iter()
         .collect::<Vec<_>>();
     Ok(res)
 }
@@ -150,14 +501,3 @@ enum ParsePhase {
     Group,
     Punct,
 }
-
-impl ParsePhase {
-    fn advance_to_next(&mut self) {
-        let next = match self {
-            ParsePhase::Ident => ParsePhase::Group,
-            ParsePhase::Group => ParsePhase::Punct,
-            ParsePhase::Punct => ParsePhase::Ident,
-        };
-        *self = next;
-    }
-}